@@ -35,7 +35,8 @@ const TYPST_VERSION: &str = env!("TYPST_VERSION");
 #[derive(Debug, Clone, Parser)]
 #[clap(name = "typst", version = TYPST_VERSION, author)]
 pub struct CliArguments {
-    /// Add additional directories to search for fonts
+    /// Add additional directories to search for fonts, which take precedence
+    /// over system fonts
     #[clap(long = "font-path", value_name = "DIR", action = ArgAction::Append)]
     font_paths: Vec<PathBuf>,
 
@@ -92,7 +93,7 @@ pub struct WatchCommand {
 /// List all discovered fonts in system and custom font paths
 #[derive(Debug, Clone, Parser)]
 pub struct FontsCommand {
-    /// Add additional directories to search for fonts
+    /// Also list style variants of each font family
     #[arg(long)]
     variants: bool,
 }
@@ -445,10 +446,10 @@ fn print_diagnostics(
 /// Execute a font listing command.
 fn fonts(command: FontsSettings) -> StrResult<()> {
     let mut searcher = FontSearcher::new();
-    searcher.search_system();
     for path in &command.font_paths {
         searcher.search_dir(path)
     }
+    searcher.search_system();
     for (name, infos) in searcher.book.families() {
         println!("{name}");
         if command.variants {
@@ -513,16 +514,18 @@ struct PathSlot {
 
 impl SystemWorld {
     fn new(root: PathBuf, font_paths: &[PathBuf], syntax_paths: &[PathBuf]) -> Self {
+        // Fonts that are found first take precedence when a family has
+        // multiple equally good variants.
         let mut searcher = FontSearcher::new();
+        for path in font_paths {
+            searcher.search_dir(path)
+        }
+
         searcher.search_system();
 
         #[cfg(feature = "embed-fonts")]
         searcher.add_embedded();
 
-        for path in font_paths {
-            searcher.search_dir(path)
-        }
-
         let mut syntax_set_builder = syntect::parsing::SyntaxSetBuilder::new();
         for path in syntax_paths {
             // TODO: Report errors
@@ -580,7 +583,7 @@ impl World for SystemWorld {
         slot.font
             .get_or_init(|| {
                 let data = self.file(&slot.path).ok()?;
-                Font::new(data, slot.index)
+                Font::new(data, slot.index).ok()
             })
             .clone()
     }
@@ -823,7 +826,18 @@ impl FontSearcher {
             let path = entry.path();
             if matches!(
                 path.extension().and_then(|s| s.to_str()),
-                Some("ttf" | "otf" | "TTF" | "OTF" | "ttc" | "otc" | "TTC" | "OTC"),
+                Some(
+                    "ttf"
+                        | "otf"
+                        | "TTF"
+                        | "OTF"
+                        | "ttc"
+                        | "otc"
+                        | "TTC"
+                        | "OTC"
+                        | "woff"
+                        | "WOFF"
+                ),
             ) {
                 self.search_file(path);
             }
@@ -835,7 +849,15 @@ impl FontSearcher {
         let path = path.as_ref();
         if let Ok(file) = File::open(path) {
             if let Ok(mmap) = unsafe { Mmap::map(&file) } {
-                for (i, info) in FontInfo::iter(&mmap).enumerate() {
+                let infos = match FontInfo::iter(&mmap) {
+                    Ok(infos) => infos,
+                    Err(err) => {
+                        eprintln!("warning: skipping font {}: {err}", path.display());
+                        return;
+                    }
+                };
+
+                for (i, info) in infos.enumerate() {
                     self.book.push(info);
                     self.fonts.push(FontSlot {
                         path: path.into(),
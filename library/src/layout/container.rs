@@ -215,6 +215,10 @@ pub struct BlockElem {
 
     /// Whether the block can be broken and continue on the next page.
     ///
+    /// An unbreakable block that doesn't fit into the remaining space on a page
+    /// moves to the next page as a whole. If it is too large to fit even onto
+    /// an empty page, it is broken regardless.
+    ///
     /// Defaults to `{true}`.
     /// ```example
     /// #set page(height: 80pt)
@@ -331,8 +335,18 @@ impl Layout for BlockElem {
             .map(|(s, b)| s.map(|v| v.relative_to(b)))
             .unwrap_or(regions.base());
 
+        // An unbreakable block with automatic height is broken anyway if it
+        // doesn't even fit into a full region, since it would overflow
+        // otherwise. To find out, it is measured with unlimited height.
+        let mut breakable = self.breakable(styles);
+        if !breakable && sizing.y == Smart::Auto {
+            let pod = Regions::one(Size::new(size.x, Abs::inf()), Axes::splat(false));
+            let frame = body.measure(vt, styles, pod)?.into_frame();
+            breakable = !regions.full.fits(frame.height());
+        }
+
         // Layout the child.
-        let mut frames = if self.breakable(styles) {
+        let mut frames = if breakable {
             // Measure to ensure frames for all regions have the same width.
             if sizing.x == Smart::Auto {
                 let pod = Regions::one(size, Axes::splat(false));
@@ -28,6 +28,11 @@ use crate::prelude::*;
 /// #thing[Welcome]
 /// ```
 ///
+/// By default, the content is measured at its natural size. To find out how
+/// large it would be when layouted into a limited area, pass the available
+/// `width` and/or `height`. The measurement runs the same layout as the real
+/// thing, so the results match exactly.
+///
 /// The measure function returns a dictionary with the entries `width` and
 /// `height`, both of type [`length`]($type/length).
 ///
@@ -40,9 +45,21 @@ pub fn measure(
     content: Content,
     /// The styles with which to layout the content.
     styles: Styles,
+    /// The width available to the content. If `{auto}`, the content is
+    /// measured at its natural width.
+    #[named]
+    #[default]
+    width: Smart<Length>,
+    /// The height available to the content. If `{auto}`, the content is
+    /// measured at its natural height.
+    #[named]
+    #[default]
+    height: Smart<Length>,
 ) -> Value {
-    let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
     let styles = StyleChain::new(&styles);
+    let size =
+        Axes::new(width, height).map(|v| v.map_or(Abs::inf(), |v| v.resolve(styles)));
+    let pod = Regions::one(size, Axes::splat(false));
     let frame = content.measure(&mut vm.vt, styles, pod)?.into_frame();
     let Size { x, y } = frame.size();
     dict! { "width" => x, "height" => y }.into()
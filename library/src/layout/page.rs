@@ -151,7 +151,16 @@ pub struct PageElem {
 
     /// How to [number]($func/numbering) the pages.
     ///
-    /// If an explicit `footer` is given, the numbering is ignored.
+    /// The numbering is displayed in the footer, or in the header if the
+    /// `number-align` is `{top}`. If an explicit `footer` (or `header`,
+    /// respectively) is given, the numbering is ignored.
+    ///
+    /// When exporting to PDF, the numbering also determines the page labels a
+    /// viewer shows in its page indicator, as long as the numbering is a
+    /// pattern whose first counting symbol is one of `1`, `a`, `A`, `i` or
+    /// `I`. To number a preface with roman numerals and the rest of the
+    /// document with arabic ones, switch the numbering and reset the
+    /// [page counter]($func/counter) with `{counter(page).update(1)}`.
     ///
     /// ```example
     /// #set page(
@@ -166,6 +175,12 @@ pub struct PageElem {
 
     /// The alignment of the page numbering.
     ///
+    /// If the vertical component is `{top}`, the numbering is placed in the
+    /// header instead of the footer. Within the header, it is then aligned
+    /// horizontally only and sits where other header content would. Within
+    /// the footer, the full alignment applies. The numbering cannot be
+    /// `{horizon}`-aligned.
+    ///
     /// ```example
     /// #set page(
     ///   margin: (top: 16pt, bottom: 24pt),
@@ -175,6 +190,15 @@ pub struct PageElem {
     ///
     /// #lorem(30)
     /// ```
+    #[parse(
+        let align = args.named::<Spanned<Axes<Option<GenAlign>>>>("number-align")?;
+        if let Some(Spanned { v, span }) = &align {
+            if v.y == Some(GenAlign::Specific(Align::Horizon)) {
+                bail!(*span, "page number cannot be `horizon`-aligned");
+            }
+        }
+        align.map(|align| align.v)
+    )]
     #[default(Align::Center.into())]
     pub number_align: Axes<Option<GenAlign>>,
 
@@ -309,21 +333,34 @@ impl PageElem {
         let fill = self.fill(styles);
         let foreground = self.foreground(styles);
         let background = self.background(styles);
-        let header = self.header(styles);
         let header_ascent = self.header_ascent(styles);
-        let footer = self.footer(styles).or_else(|| {
-            self.numbering(styles).map(|numbering| {
-                let both = match &numbering {
-                    Numbering::Pattern(pattern) => pattern.pieces() >= 2,
-                    Numbering::Func(_) => true,
-                };
-                Counter::new(CounterKey::Page)
-                    .display(Some(numbering), both)
-                    .aligned(self.number_align(styles))
-            })
-        });
         let footer_descent = self.footer_descent(styles);
 
+        // The vertical alignment of the numbering decides whether it goes
+        // into the header or the footer. In the header, it is only aligned
+        // horizontally since a top alignment would pin it to the page's edge
+        // instead of the header's baseline.
+        let numbering = self.numbering(styles);
+        let number_align = self.number_align(styles);
+        let in_header = matches!(number_align.y, Some(GenAlign::Specific(Align::Top)));
+        let numbering_marginal = numbering.clone().map(|numbering| {
+            let both = match &numbering {
+                Numbering::Pattern(pattern) => pattern.pieces() >= 2,
+                Numbering::Func(_) => true,
+            };
+            Counter::new(CounterKey::Page).display(Some(numbering), both).aligned(
+                if in_header { Axes::with_x(number_align.x) } else { number_align },
+            )
+        });
+
+        let mut header = self.header(styles);
+        let mut footer = self.footer(styles);
+        if in_header {
+            header = header.or(numbering_marginal);
+        } else {
+            footer = footer.or(numbering_marginal);
+        }
+
         // Realize overlays.
         for frame in &mut fragment {
             let size = frame.size();
@@ -362,6 +399,16 @@ impl PageElem {
                 }
             }
 
+            // Label the page like its number is displayed.
+            if let Some(numbering) = &numbering {
+                let pod = Regions::one(Size::zero(), Axes::splat(false));
+                let label = PageLabelElem::new(numbering.clone())
+                    .pack()
+                    .layout(vt, styles, pod)?
+                    .into_frame();
+                frame.push_frame(Point::zero(), label);
+            }
+
             if let Some(fill) = &fill {
                 frame.fill(fill.clone());
             }
@@ -371,6 +418,31 @@ impl PageElem {
     }
 }
 
+/// Attaches the page label for a numbering to the page it ends up on.
+///
+/// Display: Page Label
+/// Category: special
+#[element(Locatable, Show)]
+struct PageLabelElem {
+    /// The numbering of the page.
+    #[required]
+    numbering: Numbering,
+}
+
+impl Show for PageLabelElem {
+    fn show(&self, vt: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+        let location = self.0.location().unwrap();
+        let number = Counter::new(CounterKey::Page).at(vt, location)?.first();
+        let Some(label) = self.numbering().to_page_label(number) else {
+            return Ok(Content::empty());
+        };
+
+        Ok(MetaElem::new()
+            .pack()
+            .styled(MetaElem::set_data(vec![Meta::PageLabel(label)])))
+    }
+}
+
 /// A manual page break.
 ///
 /// Must not be used inside any containers.
@@ -21,6 +21,19 @@ pub struct DocumentElem {
     /// The document's authors.
     pub author: Author,
 
+    /// The document's keywords.
+    pub keywords: Keywords,
+
+    /// The document's subject, i.e. a short description of its content.
+    pub subject: Option<EcoString>,
+
+    /// The date the document was created, in the format `{"YYYY-MM-DD"}`.
+    pub created: Option<Date>,
+
+    /// The date the document was last modified, in the format
+    /// `{"YYYY-MM-DD"}`.
+    pub modified: Option<Date>,
+
     /// The page runs.
     #[internal]
     #[variadic]
@@ -52,6 +65,10 @@ impl LayoutRoot for DocumentElem {
             pages,
             title: self.title(styles),
             author: self.author(styles).0,
+            keywords: self.keywords(styles).0,
+            subject: self.subject(styles),
+            created: self.created(styles),
+            modified: self.modified(styles),
         })
     }
 }
@@ -69,3 +86,17 @@ cast_from_value! {
 cast_to_value! {
     v: Author => v.0.into()
 }
+
+/// A list of keywords.
+#[derive(Debug, Default, Clone, Hash)]
+pub struct Keywords(Vec<EcoString>);
+
+cast_from_value! {
+    Keywords,
+    v: EcoString => Self(vec![v]),
+    v: Array => Self(v.into_iter().map(Value::cast).collect::<StrResult<_>>()?),
+}
+
+cast_to_value! {
+    v: Keywords => v.0.into()
+}
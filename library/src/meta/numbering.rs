@@ -99,6 +99,33 @@ impl Numbering {
         })
     }
 
+    /// The page label that matches how this numbering displays the given
+    /// page number.
+    ///
+    /// Returns `None` if the numbering can't be expressed as a page label, as
+    /// is the case for functions and symbols.
+    pub fn to_page_label(&self, number: usize) -> Option<PageLabel> {
+        let Self::Pattern(pattern) = self else { return None };
+        let (prefix, kind, case) = pattern.pieces.first()?;
+        let style = match (kind, case) {
+            (NumberingKind::Arabic, _) => PageLabelStyle::Arabic,
+            (NumberingKind::Roman, Case::Lower) => PageLabelStyle::LowerRoman,
+            (NumberingKind::Roman, Case::Upper) => PageLabelStyle::UpperRoman,
+            // Page labels repeat letters after z instead of counting on like
+            // the letter numbering does.
+            (NumberingKind::Letter, _) if number > 26 => return None,
+            (NumberingKind::Letter, Case::Lower) => PageLabelStyle::LowerAlpha,
+            (NumberingKind::Letter, Case::Upper) => PageLabelStyle::UpperAlpha,
+            (NumberingKind::Symbol, _) => return None,
+        };
+
+        Some(PageLabel {
+            prefix: (!prefix.is_empty()).then(|| prefix.clone()),
+            style: Some(style),
+            number: NonZeroUsize::new(number)?,
+        })
+    }
+
     /// Trim the prefix suffix if this is a pattern.
     pub fn trimmed(mut self) -> Self {
         if let Self::Pattern(pattern) = &mut self {
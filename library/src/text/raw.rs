@@ -1,6 +1,7 @@
 use once_cell::sync::Lazy;
 use syntect::highlighting as synt;
 use typst::syntax::{self, LinkedNode};
+use unicode_segmentation::UnicodeSegmentation;
 
 use super::{
     FontFamily, FontList, Hyphenate, LinebreakElem, SmartQuoteElem, TextElem, TextSize,
@@ -101,6 +102,22 @@ pub struct RawElem {
     /// ```
     /// ````
     pub lang: Option<EcoString>,
+
+    /// The size for a tab stop in spaces. A tab is replaced with enough spaces
+    /// to align with the next multiple of the size. Since raw text is set in a
+    /// monospace font, this aligns tabs across lines.
+    ///
+    /// ````example
+    /// #set raw(tab-size: 8)
+    /// ```tsv
+    /// Year	Month	Day
+    /// 2000	2	3
+    /// 2001	2	1
+    /// 2002	3	10
+    /// ```
+    /// ````
+    #[default(2)]
+    pub tab_size: usize,
 }
 
 impl RawElem {
@@ -135,7 +152,11 @@ impl Synthesize for RawElem {
 
 impl Show for RawElem {
     fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
-        let text = self.text();
+        let mut text = self.text();
+        if text.contains('\t') {
+            text = align_tabs(&text, self.tab_size(styles));
+        }
+
         let lang = self.lang(styles).as_ref().map(|s| s.to_lowercase());
         let foreground = THEME
             .settings
@@ -206,6 +227,34 @@ impl Finalize for RawElem {
     }
 }
 
+/// Replace tabs with spaces to align with multiples of `tab_size`.
+fn align_tabs(text: &str, tab_size: usize) -> EcoString {
+    let replacement = " ".repeat(tab_size);
+    let divisor = tab_size.max(1);
+    let mut res = EcoString::new();
+    let mut column = 0;
+
+    for grapheme in text.graphemes(true) {
+        match grapheme {
+            "\t" => {
+                let required = tab_size - column % divisor;
+                res.push_str(&replacement[..required]);
+                column += required;
+            }
+            "\n" | "\r\n" => {
+                res.push_str(grapheme);
+                column = 0;
+            }
+            _ => {
+                res.push_str(grapheme);
+                column += 1;
+            }
+        }
+    }
+
+    res
+}
+
 /// Highlight a syntax node in a theme by calling `f` with ranges and their
 /// styles.
 fn highlight_themed<F>(
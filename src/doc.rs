@@ -1,6 +1,6 @@
 //! Finished documents.
 
-use std::fmt::{self, Debug, Formatter, Write};
+use std::fmt::{self, Debug, Display, Formatter, Write};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -26,6 +26,14 @@ pub struct Document {
     pub title: Option<EcoString>,
     /// The document's author.
     pub author: Vec<EcoString>,
+    /// The document's keywords.
+    pub keywords: Vec<EcoString>,
+    /// The document's subject.
+    pub subject: Option<EcoString>,
+    /// The date the document was created.
+    pub created: Option<Date>,
+    /// The date the document was last modified.
+    pub modified: Option<Date>,
 }
 
 /// A finished layout with items at fixed positions.
@@ -598,6 +606,73 @@ cast_to_value! {
     v: Region => v.as_str().into()
 }
 
+/// A calendar date, used for document metadata.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date {
+    /// The year, e.g. 2023.
+    pub year: u16,
+    /// The month, from 1 to 12.
+    pub month: u8,
+    /// The day of the month, from 1 to 31.
+    pub day: u8,
+}
+
+impl FromStr for Date {
+    type Err = &'static str;
+
+    /// Construct a date from its ISO 8601 `YYYY-MM-DD` representation.
+    fn from_str(iso: &str) -> Result<Self, Self::Err> {
+        const ERR: &str = "expected date in the format YYYY-MM-DD";
+        let mut parts = iso.split('-');
+        let mut next = |len: usize| {
+            parts
+                .next()
+                .filter(|part| {
+                    part.len() == len && part.bytes().all(|b| b.is_ascii_digit())
+                })
+                .and_then(|part| part.parse::<u16>().ok())
+                .ok_or(ERR)
+        };
+
+        let year = next(4)?;
+        let month = next(2)?;
+        let day = next(2)?;
+        if parts.next().is_some() {
+            return Err(ERR);
+        }
+
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return Err("month must be between 1 and 12"),
+        };
+
+        if day == 0 || day > days {
+            return Err("day is out of range for the month");
+        }
+
+        Ok(Self { year, month: month as u8, day: day as u8 })
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+cast_from_value! {
+    Date,
+    string: EcoString => Self::from_str(&string)?,
+}
+
+cast_to_value! {
+    v: Date => v.to_string().into()
+}
+
 /// Meta information that isn't visible or renderable.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum Meta {
@@ -606,6 +681,8 @@ pub enum Meta {
     /// An identifiable element that produces something within the area this
     /// metadata is attached to.
     Elem(Content),
+    /// The label of the page this metadata is attached to.
+    PageLabel(PageLabel),
     /// Indicates that content should be hidden. This variant doesn't appear
     /// in the final frames as it is removed alongside the content that should
     /// be hidden.
@@ -616,6 +693,43 @@ cast_from_value! {
     Meta: "meta",
 }
 
+/// How a page is labelled in a viewer's page indicator.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PageLabel {
+    /// A string prepended to the number, if any.
+    pub prefix: Option<EcoString>,
+    /// How the number is displayed. If this is `None`, the label consists of
+    /// just the prefix.
+    pub style: Option<PageLabelStyle>,
+    /// The number of the page.
+    pub number: NonZeroUsize,
+}
+
+impl PageLabel {
+    /// Whether this label continues the label range of `prev`, that is, it
+    /// has the same prefix and style and the next number.
+    pub fn continues(&self, prev: &Self) -> bool {
+        self.prefix == prev.prefix
+            && self.style == prev.style
+            && self.number.get() == prev.number.get() + 1
+    }
+}
+
+/// The numbering style of a page label.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PageLabelStyle {
+    /// Decimal arabic numerals (1, 2, 3).
+    Arabic,
+    /// Lowercase roman numerals (i, ii, iii).
+    LowerRoman,
+    /// Uppercase roman numerals (I, II, III).
+    UpperRoman,
+    /// Lowercase letters (a to z for the first 26 pages, then aa to zz).
+    LowerAlpha,
+    /// Uppercase letters (A to Z for the first 26 pages, then AA to ZZ).
+    UpperAlpha,
+}
+
 /// A link destination.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Destination {
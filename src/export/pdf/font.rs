@@ -1,12 +1,13 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap};
 use pdf_writer::{Filter, Finish, Name, Rect, Str};
+use ttf_parser::gsub::SubstitutionSubtable;
 use ttf_parser::{name_id, GlyphId, Tag};
 
 use super::{deflate, EmExt, PdfContext, RefExt};
-use crate::util::SliceExt;
+use crate::util::{hash128, SliceExt};
 
 /// Embed all used fonts into the PDF.
 pub fn write_fonts(ctx: &mut PdfContext) {
@@ -26,7 +27,8 @@ pub fn write_fonts(ctx: &mut PdfContext) {
             .find_name(name_id::POST_SCRIPT_NAME)
             .unwrap_or_else(|| "unknown".to_string());
 
-        let base_font = eco_format!("ABCDEF+{}", postscript_name);
+        let subset_tag = subset_tag(glyphs);
+        let base_font = eco_format!("{subset_tag}+{postscript_name}");
         let base_font = Name(base_font.as_bytes());
         let cmap_name = Name(b"Custom");
         let system_info = SystemInfo {
@@ -131,7 +133,7 @@ pub fn write_fonts(ctx: &mut PdfContext) {
 
         // Compute a reverse mapping from glyphs to unicode.
         let cmap = {
-            let mut mapping = BTreeMap::new();
+            let mut reverse = BTreeMap::new();
             for subtable in
                 ttf.tables().cmap.into_iter().flat_map(|table| table.subtables)
             {
@@ -139,18 +141,32 @@ pub fn write_fonts(ctx: &mut PdfContext) {
                     subtable.codepoints(|n| {
                         if let Some(c) = std::char::from_u32(n) {
                             if let Some(GlyphId(g)) = ttf.glyph_index(c) {
-                                if glyphs.contains(&g) {
-                                    mapping.insert(g, c);
-                                }
+                                reverse.insert(g, c);
                             }
                         }
                     });
                 }
             }
 
+            let mut mapping: BTreeMap<u16, EcoString> = reverse
+                .iter()
+                .filter(|(g, _)| glyphs.contains(g))
+                .map(|(&g, &c)| (g, c.into()))
+                .collect();
+
+            // Ligature glyphs aren't in the character map, so we map them
+            // back to the characters of their components.
+            if mapping.len() < glyphs.len() {
+                for (g, text) in ligatures(ttf, &reverse) {
+                    if glyphs.contains(&g) {
+                        mapping.entry(g).or_insert(text);
+                    }
+                }
+            }
+
             let mut cmap = UnicodeCmap::new(cmap_name, system_info);
-            for (g, c) in mapping {
-                cmap.pair(g, c);
+            for (g, text) in mapping {
+                cmap.pair_with_multiple(g, text.chars());
             }
             cmap
         };
@@ -182,3 +198,63 @@ pub fn write_fonts(ctx: &mut PdfContext) {
         stream.finish();
     }
 }
+
+/// Find all ligature glyphs in the font's GSUB table along with the text they
+/// stand for.
+fn ligatures(
+    ttf: &ttf_parser::Face,
+    reverse: &BTreeMap<u16, char>,
+) -> Vec<(u16, EcoString)> {
+    let mut ligatures = vec![];
+    let Some(gsub) = ttf.tables().gsub else { return ligatures };
+
+    for lookup in gsub.lookups {
+        for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+            let SubstitutionSubtable::Ligature(subst) = subtable else { continue };
+            for (&first, &c) in reverse {
+                let Some(set) = subst
+                    .coverage
+                    .get(GlyphId(first))
+                    .and_then(|index| subst.ligature_sets.get(index))
+                else {
+                    continue;
+                };
+
+                for ligature in set {
+                    let text: Option<EcoString> = std::iter::once(Some(c))
+                        .chain(
+                            ligature
+                                .components
+                                .into_iter()
+                                .map(|GlyphId(g)| reverse.get(&g).copied()),
+                        )
+                        .collect();
+
+                    if let Some(text) = text {
+                        ligatures.push((ligature.glyph.0, text));
+                    }
+                }
+            }
+        }
+    }
+
+    ligatures
+}
+
+/// Produce a unique 6 letter tag for a glyph set.
+///
+/// PDF readers may treat font subsets with the same tag and name as the same
+/// font, so the tag must differ between differing subsets of a font.
+fn subset_tag(glyphs: &HashSet<u16>) -> EcoString {
+    const LEN: usize = 6;
+    const BASE: u128 = 26;
+    let mut sorted: Vec<_> = glyphs.iter().copied().collect();
+    sorted.sort_unstable();
+    let mut hash = hash128(&sorted);
+    let mut letter = [b'A'; LEN];
+    for l in letter.iter_mut() {
+        *l = b'A' + (hash % BASE) as u8;
+        hash /= BASE;
+    }
+    std::str::from_utf8(&letter).unwrap().into()
+}
@@ -8,14 +8,15 @@ mod page;
 use std::cmp::Eq;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
+use std::num::NonZeroUsize;
 
 use pdf_writer::types::Direction;
-use pdf_writer::{Finish, Name, PdfWriter, Ref, TextStr};
-use xmp_writer::{LangId, RenditionClass, XmpWriter};
+use pdf_writer::{Dict, Finish, Name, PdfWriter, Ref, TextStr};
+use xmp_writer::{DateTime, LangId, RenditionClass, XmpWriter};
 
 use self::outline::HeadingNode;
 use self::page::Page;
-use crate::doc::{Document, Lang};
+use crate::doc::{Date, Document, Lang, PageLabel, PageLabelStyle};
 use crate::font::Font;
 use crate::geom::{Abs, Dir, Em};
 use crate::image::Image;
@@ -45,6 +46,7 @@ pub struct PdfContext<'a> {
     writer: PdfWriter,
     pages: Vec<Page>,
     page_heights: Vec<f32>,
+    page_labels: Vec<Option<PageLabel>>,
     alloc: Ref,
     page_tree_ref: Ref,
     font_refs: Vec<Ref>,
@@ -67,6 +69,7 @@ impl<'a> PdfContext<'a> {
             writer: PdfWriter::new(),
             pages: vec![],
             page_heights: vec![],
+            page_labels: vec![],
             alloc,
             page_tree_ref,
             page_refs: vec![],
@@ -131,6 +134,29 @@ fn write_catalog(ctx: &mut PdfContext) {
         info.author(TextStr(&authors.join(", ")));
         xmp.creator(authors.iter().map(|s| s.as_str()));
     }
+
+    let keywords = &ctx.document.keywords;
+    if !keywords.is_empty() {
+        let joined = keywords.join(", ");
+        info.keywords(TextStr(&joined));
+        xmp.pdf_keywords(&joined);
+    }
+
+    if let Some(subject) = &ctx.document.subject {
+        info.subject(TextStr(subject));
+        xmp.description([(None, subject.as_str())]);
+    }
+
+    if let Some(date) = ctx.document.created {
+        info.creation_date(pdf_date(date));
+        xmp.create_date(xmp_date(date));
+    }
+
+    if let Some(date) = ctx.document.modified {
+        info.modified_date(pdf_date(date));
+        xmp.modify_date(xmp_date(date));
+    }
+
     info.creator(TextStr("Typst"));
     info.finish();
     xmp.creator_tool("Typst");
@@ -160,6 +186,68 @@ fn write_catalog(ctx: &mut PdfContext) {
     if let Some(lang) = lang {
         catalog.lang(TextStr(lang.as_str()));
     }
+
+    if ctx.page_labels.iter().any(Option::is_some) {
+        write_page_labels(
+            &mut catalog.insert(Name(b"PageLabels")).dict(),
+            &ctx.page_labels,
+        );
+    }
+}
+
+/// Convert a date into the PDF date format.
+fn pdf_date(date: Date) -> pdf_writer::Date {
+    pdf_writer::Date::new(date.year).month(date.month).day(date.day)
+}
+
+/// Convert a date into the XMP date format.
+fn xmp_date(date: Date) -> DateTime {
+    DateTime::date(date.year, date.month, date.day)
+}
+
+/// Write the page label number tree.
+///
+/// Consecutive pages whose labels continue each other share one label range.
+/// Pages without a label are labelled with their physical page number.
+fn write_page_labels(tree: &mut Dict, labels: &[Option<PageLabel>]) {
+    let mut nums = tree.insert(Name(b"Nums")).array();
+    let mut prev: Option<PageLabel> = None;
+    for (i, label) in labels.iter().enumerate() {
+        let label = label.clone().unwrap_or_else(|| PageLabel {
+            prefix: None,
+            style: Some(PageLabelStyle::Arabic),
+            number: NonZeroUsize::new(i + 1).unwrap(),
+        });
+
+        if prev.as_ref().map_or(false, |prev| label.continues(prev)) {
+            prev = Some(label);
+            continue;
+        }
+
+        nums.item(i as i32);
+        let mut entry = nums.push().dict();
+        entry.pair(Name(b"Type"), Name(b"PageLabel"));
+        if let Some(style) = label.style {
+            entry.pair(
+                Name(b"S"),
+                Name(match style {
+                    PageLabelStyle::Arabic => b"D",
+                    PageLabelStyle::LowerRoman => b"r",
+                    PageLabelStyle::UpperRoman => b"R",
+                    PageLabelStyle::LowerAlpha => b"a",
+                    PageLabelStyle::UpperAlpha => b"A",
+                }),
+            );
+        }
+        if let Some(prefix) = &label.prefix {
+            entry.pair(Name(b"P"), TextStr(prefix));
+        }
+        if label.number.get() != 1 {
+            entry.pair(Name(b"St"), label.number.get() as i32);
+        }
+        entry.finish();
+        prev = Some(label);
+    }
 }
 
 /// Compress data with the DEFLATE algorithm.
@@ -246,3 +334,106 @@ impl RefExt for Ref {
         prev
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::{Frame, FrameItem, Meta};
+    use crate::geom::{Point, Size};
+
+    #[test]
+    fn test_pdf_keywords() {
+        let document = Document {
+            pages: vec![Frame::new(Size::new(Abs::pt(100.0), Abs::pt(100.0)))],
+            keywords: vec!["typesetting".into(), "markup".into()],
+            ..Document::default()
+        };
+
+        let pdf = String::from_utf8_lossy(&pdf(&document)).into_owned();
+        assert!(pdf.contains("/Keywords (typesetting, markup)"));
+        assert!(pdf.contains("typesetting, markup</pdf:Keywords>"));
+    }
+
+    #[test]
+    fn test_pdf_keywords_non_ascii() {
+        let document = Document {
+            pages: vec![Frame::new(Size::new(Abs::pt(100.0), Abs::pt(100.0)))],
+            keywords: vec!["typesetting".into(), "組版".into()],
+            ..Document::default()
+        };
+
+        // Non-ASCII text strings are encoded as UTF-16BE with a byte order mark.
+        let hex: String = "typesetting, 組版"
+            .encode_utf16()
+            .map(|unit| format!("{unit:04X}"))
+            .collect();
+
+        let pdf = String::from_utf8_lossy(&pdf(&document)).into_owned();
+        assert!(pdf.to_uppercase().contains(&format!("/KEYWORDS <FEFF{hex}>")));
+        assert!(pdf.contains("typesetting, 組版</pdf:Keywords>"));
+    }
+
+    #[test]
+    fn test_pdf_subject_and_dates() {
+        let document = Document {
+            pages: vec![Frame::new(Size::new(Abs::pt(100.0), Abs::pt(100.0)))],
+            subject: Some("A test document".into()),
+            created: Some("2023-04-15".parse().unwrap()),
+            modified: Some("2023-05-02".parse().unwrap()),
+            ..Document::default()
+        };
+
+        let pdf = String::from_utf8_lossy(&pdf(&document)).into_owned();
+        assert!(pdf.contains("/Subject (A test document)"));
+        assert!(pdf.contains("/CreationDate (D:20230415"));
+        assert!(pdf.contains("/ModDate (D:20230502"));
+        assert!(pdf.contains("dc:description"));
+        assert!(pdf.contains("A test document</rdf:li>"));
+        assert!(pdf.contains("2023-04-15</xmp:CreateDate>"));
+        assert!(pdf.contains("2023-05-02</xmp:ModifyDate>"));
+    }
+
+    #[test]
+    fn test_date_from_str() {
+        let date: Date = "2024-02-29".parse().unwrap();
+        assert_eq!((date.year, date.month, date.day), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2023-13-01".parse::<Date>().is_err());
+        assert!("2023-4-15".parse::<Date>().is_err());
+        assert!("2023-04-15T12:00".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn test_pdf_page_labels() {
+        let page = |style, number| {
+            let size = Size::new(Abs::pt(100.0), Abs::pt(100.0));
+            let mut frame = Frame::new(size);
+            let number = NonZeroUsize::new(number).unwrap();
+            let label = PageLabel { prefix: None, style: Some(style), number };
+            frame.push(Point::zero(), FrameItem::Meta(Meta::PageLabel(label), size));
+            frame
+        };
+
+        let document = Document {
+            pages: vec![
+                page(PageLabelStyle::LowerRoman, 1),
+                page(PageLabelStyle::LowerRoman, 2),
+                page(PageLabelStyle::LowerRoman, 3),
+                page(PageLabelStyle::Arabic, 1),
+                page(PageLabelStyle::Arabic, 2),
+            ],
+            ..Document::default()
+        };
+
+        let pdf = String::from_utf8_lossy(&pdf(&document)).into_owned();
+        let start = pdf.find("/PageLabels").unwrap();
+        let end = start + pdf[start..].find(']').unwrap();
+        let labels = &pdf[start..end];
+        assert!(labels.contains("0 <<"));
+        assert!(labels.contains("3 <<"));
+        assert!(!labels.contains("1 <<"));
+        assert!(labels.find("/S /r").unwrap() < labels.find("/S /D").unwrap());
+        assert!(!labels.contains("/St"));
+    }
+}
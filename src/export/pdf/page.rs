@@ -4,7 +4,7 @@ use pdf_writer::writers::ColorSpace;
 use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str};
 
 use super::{deflate, AbsExt, EmExt, PdfContext, RefExt, D65_GRAY, SRGB};
-use crate::doc::{Destination, Frame, FrameItem, GroupItem, Meta, TextItem};
+use crate::doc::{Destination, Frame, FrameItem, GroupItem, Meta, PageLabel, TextItem};
 use crate::font::Font;
 use crate::geom::{
     self, Abs, Color, Em, Geometry, Numeric, Paint, Point, Ratio, Shape, Size, Stroke,
@@ -33,6 +33,7 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
         saves: vec![],
         bottom: 0.0,
         links: vec![],
+        label: None,
     };
 
     let size = frame.size();
@@ -59,6 +60,7 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
     };
 
     ctx.parent.pages.push(page);
+    ctx.parent.page_labels.push(ctx.label);
 }
 
 /// Write the page tree.
@@ -168,6 +170,7 @@ struct PageContext<'a, 'b> {
     saves: Vec<State>,
     bottom: f32,
     links: Vec<(Destination, Rect)>,
+    label: Option<PageLabel>,
 }
 
 /// A simulated graphics state used to deduplicate graphics state changes and
@@ -297,6 +300,7 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
             FrameItem::Meta(meta, size) => match meta {
                 Meta::Link(dest) => write_link(ctx, pos, dest, *size),
                 Meta::Elem(_) => {}
+                Meta::PageLabel(label) => ctx.label = Some(label.clone()),
                 Meta::Hide => {}
             },
         }
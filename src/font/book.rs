@@ -1,11 +1,14 @@
+use std::borrow::Cow;
 use std::cmp::Reverse;
 use std::collections::BTreeMap;
+use std::ops::Range;
 
 use serde::{Deserialize, Serialize};
 use ttf_parser::{name_id, PlatformId, Tag};
 use unicode_segmentation::UnicodeSegmentation;
 
-use super::{Font, FontStretch, FontStyle, FontVariant, FontWeight};
+use super::{woff, Font, FontStretch, FontStyle, FontVariant, FontWeight};
+use crate::diag::StrResult;
 
 /// Metadata about a collection of fonts.
 #[derive(Default, Clone, Hash)]
@@ -56,7 +59,8 @@ impl FontBook {
     /// Try to find and load a font from the given `family` that matches
     /// the given `variant` as closely as possible.
     ///
-    /// The `family` should be all lowercase.
+    /// The `family` should be all lowercase. If multiple fonts match equally
+    /// well, the one that was pushed first is selected.
     pub fn select(&self, family: &str, variant: FontVariant) -> Option<usize> {
         let ids = self.families.get(family)?;
         self.find_best_variant(None, variant, ids.iter().copied())
@@ -106,6 +110,8 @@ impl FontBook {
     ///   normal.
     /// - The absolute distance to the target stretch.
     /// - The absolute distance to the target weight.
+    /// - Whether the weight lies in the preferred direction in case of a tie,
+    ///   following the CSS font matching rules.
     fn find_best_variant(
         &self,
         like: Option<&FontInfo>,
@@ -131,6 +137,7 @@ impl FontBook {
                 current.variant.style.distance(variant.style),
                 current.variant.stretch.distance(variant.stretch),
                 current.variant.weight.distance(variant.weight),
+                !variant.weight.prefers(current.variant.weight),
             );
 
             if best_key.map_or(true, |b| key < b) {
@@ -171,12 +178,21 @@ bitflags::bitflags! {
 
 impl FontInfo {
     /// Compute metadata for all fonts in the given data.
-    pub fn iter(data: &[u8]) -> impl Iterator<Item = FontInfo> + '_ {
-        let count = ttf_parser::fonts_in_collection(data).unwrap_or(1);
-        (0..count).filter_map(move |index| {
-            let ttf = ttf_parser::Face::parse(data, index).ok()?;
+    ///
+    /// Fails if the data is a WOFF file that can't be decoded. Faces that
+    /// fail to parse are skipped.
+    pub fn iter(data: &[u8]) -> StrResult<impl Iterator<Item = FontInfo> + '_> {
+        let data = if woff::is_woff(data) {
+            Cow::Owned(woff::decode(data)?)
+        } else {
+            Cow::Borrowed(data)
+        };
+
+        let count = ttf_parser::fonts_in_collection(&data).unwrap_or(1);
+        Ok((0..count).filter_map(move |index| {
+            let ttf = ttf_parser::Face::parse(&data, index).ok()?;
             Self::from_ttf(&ttf)
-        })
+        }))
     }
 
     /// Compute metadata for a single ttf-parser face.
@@ -463,10 +479,15 @@ impl Coverage {
 
     /// Iterate over all covered codepoints.
     pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.ranges().flatten()
+    }
+
+    /// Iterate over the ranges of consecutive covered codepoints.
+    pub fn ranges(&self) -> impl Iterator<Item = Range<u32>> + '_ {
         let mut inside = false;
         let mut cursor = 0;
-        self.0.iter().flat_map(move |run| {
-            let range = if inside { cursor..cursor + run } else { 0..0 };
+        self.0.iter().filter_map(move |run| {
+            let range = inside.then(|| cursor..cursor + run);
             inside = !inside;
             cursor += run;
             range
@@ -496,6 +517,48 @@ mod tests {
         assert_eq!(typographic_family("Font Ultra Bold"), "Font");
     }
 
+    #[test]
+    fn test_select_weight() {
+        let mut book = FontBook::new();
+        for weight in [400, 700, 200] {
+            book.push(FontInfo {
+                family: "Test".into(),
+                variant: FontVariant {
+                    weight: FontWeight::from_number(weight),
+                    ..Default::default()
+                },
+                flags: FontFlags::empty(),
+                coverage: Coverage::from_vec(vec![]),
+            });
+        }
+
+        let select = |book: &FontBook, weight| {
+            let variant = FontVariant {
+                weight: FontWeight::from_number(weight),
+                ..Default::default()
+            };
+            book.select("test", variant)
+        };
+
+        assert_eq!(select(&book, 600), Some(1));
+        assert_eq!(select(&book, 550), Some(1));
+        assert_eq!(select(&book, 300), Some(2));
+        assert_eq!(select(&book, 400), Some(0));
+
+        // A duplicate doesn't take precedence over the earlier font.
+        book.push(book.infos[0].clone());
+        assert_eq!(select(&book, 400), Some(0));
+    }
+
+    #[test]
+    fn test_coverage_ranges() {
+        let coverage = Coverage::from_vec(vec![2, 3, 4, 9, 0x1F600, 0x1F601]);
+        let ranges: Vec<_> = coverage.ranges().collect();
+        assert_eq!(ranges, [2..5, 9..10, 0x1F600..0x1F602]);
+        assert!(coverage.contains(0x1F601));
+        assert!(!coverage.contains(0x1F602));
+    }
+
     #[test]
     fn test_coverage() {
         #[track_caller]
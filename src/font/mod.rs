@@ -2,18 +2,22 @@
 
 mod book;
 mod variant;
+mod woff;
 
 pub use self::book::*;
 pub use self::variant::*;
 
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
+use ecow::eco_format;
 use ttf_parser::GlyphId;
 
+use crate::diag::StrResult;
 use crate::eval::Cast;
-use crate::geom::Em;
+use crate::geom::{Abs, Angle, Em};
 use crate::util::Buffer;
 
 /// An OpenType font.
@@ -36,11 +40,18 @@ struct Repr {
     ttf: ttf_parser::Face<'static>,
     /// The underlying rustybuzz face.
     rusty: rustybuzz::Face<'static>,
+    /// Whether all of the font's glyphs have the same width.
+    monospace: bool,
 }
 
 impl Font {
     /// Parse a font from data and collection index.
-    pub fn new(data: Buffer, index: u32) -> Option<Self> {
+    ///
+    /// WOFF files are decoded transparently.
+    pub fn new(data: Buffer, index: u32) -> StrResult<Self> {
+        let data =
+            if woff::is_woff(&data) { Buffer::from(woff::decode(&data)?) } else { data };
+
         // Safety:
         // - The slices's location is stable in memory:
         //   - We don't move the underlying vector
@@ -50,18 +61,24 @@ impl Font {
         let slice: &'static [u8] =
             unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
 
-        let ttf = ttf_parser::Face::parse(slice, index).ok()?;
-        let rusty = rustybuzz::Face::from_slice(slice, index)?;
+        let ttf = ttf_parser::Face::parse(slice, index)
+            .map_err(|err| eco_format!("failed to parse font: {err}"))?;
+        let rusty = rustybuzz::Face::from_slice(slice, index)
+            .ok_or("failed to parse font for shaping")?;
         let metrics = FontMetrics::from_ttf(&ttf);
-        let info = FontInfo::from_ttf(&ttf)?;
+        let info = FontInfo::from_ttf(&ttf).ok_or("font has no usable family name")?;
+        let monospace =
+            info.flags.contains(FontFlags::MONOSPACE) || has_uniform_advances(&ttf);
 
-        Some(Self(Arc::new(Repr { data, index, info, metrics, ttf, rusty })))
+        Ok(Self(Arc::new(Repr { data, index, info, metrics, ttf, rusty, monospace })))
     }
 
     /// Parse all fonts in the given data.
+    ///
+    /// Fonts that fail to parse are skipped.
     pub fn iter(data: Buffer) -> impl Iterator<Item = Self> {
         let count = ttf_parser::fonts_in_collection(&data).unwrap_or(1);
-        (0..count).filter_map(move |index| Self::new(data.clone(), index))
+        (0..count).filter_map(move |index| Self::new(data.clone(), index).ok())
     }
 
     /// The underlying buffer.
@@ -79,6 +96,37 @@ impl Font {
         &self.0.info
     }
 
+    /// Whether all of the font's glyphs have the same width.
+    ///
+    /// This is the case if the font declares itself as fixed pitch, as
+    /// reflected by [`FontFlags::MONOSPACE`], or if all printable ASCII
+    /// characters have the same advance.
+    pub fn is_monospace(&self) -> bool {
+        self.0.monospace
+    }
+
+    /// The horizontal advance of a space at the given font size.
+    ///
+    /// In a monospace font, this is the width of every character column.
+    pub fn advance_width(&self, size: Abs) -> Option<Abs> {
+        let glyph = self.0.ttf.glyph_index(' ')?;
+        self.advance(glyph.0).map(|advance| advance.at(size))
+    }
+
+    /// Whether the font has a glyph for the character.
+    pub fn covers(&self, c: char) -> bool {
+        self.0.info.coverage.contains(c as u32)
+    }
+
+    /// The ranges of characters the font has glyphs for, in ascending order.
+    pub fn coverage(&self) -> impl Iterator<Item = RangeInclusive<char>> + '_ {
+        self.0.info.coverage.ranges().filter_map(|range| {
+            let start = char::from_u32(range.start)?;
+            let end = char::from_u32(range.end - 1)?;
+            Some(start..=end)
+        })
+    }
+
     /// The font's metrics.
     pub fn metrics(&self) -> &FontMetrics {
         &self.0.metrics
@@ -102,6 +150,41 @@ impl Font {
             .map(|units| self.to_em(units))
     }
 
+    /// Look up the vertical advance height of a glyph.
+    ///
+    /// Falls back to one em if the font has no vertical metrics.
+    pub fn vertical_advance(&self, glyph: u16) -> Option<Em> {
+        let id = GlyphId(glyph);
+        if id.0 >= self.0.ttf.number_of_glyphs() {
+            return None;
+        }
+
+        Some(
+            self.0
+                .ttf
+                .glyph_ver_advance(id)
+                .map_or(Em::one(), |units| self.to_em(units)),
+        )
+    }
+
+    /// Look up the vertical origin of a glyph, that is, the distance from the
+    /// baseline to the top of the glyph's vertical layout box.
+    ///
+    /// Falls back to the font's ascender if the font has no vertical metrics.
+    pub fn vertical_origin(&self, glyph: u16) -> Option<Em> {
+        let id = GlyphId(glyph);
+        if id.0 >= self.0.ttf.number_of_glyphs() {
+            return None;
+        }
+
+        Some(
+            self.0
+                .ttf
+                .glyph_y_origin(id)
+                .map_or(self.0.metrics.ascender, |units| self.to_em(units)),
+        )
+    }
+
     /// Lookup a name by id.
     pub fn find_name(&self, id: u16) -> Option<String> {
         find_name(&self.0.ttf, id)
@@ -156,6 +239,11 @@ pub struct FontMetrics {
     pub x_height: Em,
     /// The distance from the baseline to the typographic descender.
     pub descender: Em,
+    /// The recommended additional space between lines.
+    pub line_gap: Em,
+    /// The angle of upright strokes, counter-clockwise from the vertical.
+    /// Negative for fonts leaning to the right and zero for upright fonts.
+    pub italic_angle: Angle,
     /// Recommended metrics for a strikethrough line.
     pub strikethrough: LineMetrics,
     /// Recommended metrics for an underline.
@@ -174,6 +262,8 @@ impl FontMetrics {
         let cap_height = ttf.capital_height().filter(|&h| h > 0).map_or(ascender, to_em);
         let x_height = ttf.x_height().filter(|&h| h > 0).map_or(ascender, to_em);
         let descender = to_em(ttf.typographic_descender().unwrap_or(ttf.descender()));
+        let line_gap = to_em(ttf.typographic_line_gap().unwrap_or(ttf.line_gap()));
+        let italic_angle = Angle::deg(ttf.italic_angle().map_or(0.0, f64::from));
         let strikeout = ttf.strikeout_metrics();
         let underline = ttf.underline_metrics();
 
@@ -202,6 +292,8 @@ impl FontMetrics {
             cap_height,
             x_height,
             descender,
+            line_gap,
+            italic_angle,
             strikethrough,
             underline,
             overline,
@@ -244,3 +336,113 @@ pub enum VerticalFontMetric {
     /// The font's ascender, which typically exceeds the depth of all glyphs.
     Descender,
 }
+
+/// Whether all printable ASCII characters have the same advance, which
+/// detects monospace fonts that don't set the `isFixedPitch` flag.
+fn has_uniform_advances(ttf: &ttf_parser::Face) -> bool {
+    let mut advances =
+        ('!'..='~').map(|c| ttf.glyph_index(c).and_then(|id| ttf.glyph_hor_advance(id)));
+    match advances.next() {
+        Some(Some(first)) if first > 0 => advances.all(|advance| advance == Some(first)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_metrics() {
+        let load = |data: &'static [u8]| Font::new(Buffer::from(data), 0).unwrap();
+        let regular = load(include_bytes!("../../assets/fonts/LinLibertine_R.ttf"));
+        let italic = load(include_bytes!("../../assets/fonts/LinLibertine_RI.ttf"));
+
+        let metrics = regular.metrics();
+        assert!(metrics.descender < Em::zero());
+        assert!(metrics.x_height < metrics.cap_height);
+        assert!(metrics.cap_height <= metrics.ascender);
+        assert!(metrics.line_gap >= Em::zero());
+        assert_eq!(metrics.italic_angle, Angle::zero());
+        assert!(italic.metrics().italic_angle < Angle::zero());
+    }
+
+    #[test]
+    fn test_font_vertical_metrics() {
+        let load = |data: &'static [u8]| Font::new(Buffer::from(data), 0).unwrap();
+        let cjk = load(include_bytes!("../../assets/fonts/NotoSerifCJKsc-Regular.otf"));
+        let latin = load(include_bytes!("../../assets/fonts/PTSans-Regular.ttf"));
+
+        let glyph = cjk.ttf().glyph_index('中').unwrap().0;
+        assert_eq!(cjk.vertical_advance(glyph), Some(Em::one()));
+        assert!(cjk.vertical_origin(glyph).unwrap() > Em::zero());
+
+        let glyph = latin.ttf().glyph_index('a').unwrap().0;
+        assert_eq!(latin.vertical_advance(glyph), Some(Em::one()));
+        assert_eq!(latin.vertical_origin(glyph), Some(latin.metrics().ascender));
+        assert_eq!(latin.vertical_advance(u16::MAX), None);
+    }
+
+    #[test]
+    fn test_font_decode_errors() {
+        let woff = Buffer::from_static(b"wOFF");
+        let woff2 = Buffer::from_static(b"wOF2");
+        assert_eq!(
+            Font::new(woff.clone(), 0).err(),
+            Some("WOFF file is truncated".into())
+        );
+        assert_eq!(FontInfo::iter(&woff).err(), Some("WOFF file is truncated".into()));
+        assert_eq!(
+            Font::new(woff2, 0).err(),
+            Some("WOFF2 fonts are not supported".into())
+        );
+        assert!(Font::new(Buffer::from_static(b"garbage"), 0).is_err());
+    }
+
+    #[test]
+    fn test_font_coverage() {
+        let load = |data: &'static [u8]| Font::new(Buffer::from(data), 0).unwrap();
+        let arabic =
+            load(include_bytes!("../../assets/fonts/NotoSansArabic-Regular.ttf"));
+        let ranges: Vec<_> = arabic.coverage().collect();
+        assert!(ranges.iter().any(|range| range.contains(&'\u{627}')));
+        assert!(!ranges.iter().any(|range| range.contains(&'あ')));
+        assert!(arabic.covers('\u{627}'));
+        assert!(!arabic.covers('あ'));
+        assert!(ranges.windows(2).all(|w| w[0].end() < w[1].start()));
+
+        // Characters outside the BMP come from format 12 subtables.
+        let symbols =
+            load(include_bytes!("../../assets/fonts/NotoSansSymbols2-Regular.ttf"));
+        let astral =
+            symbols.coverage().find(|range| *range.start() > '\u{FFFF}').unwrap();
+        assert!(symbols.covers(*astral.start()));
+        assert!(symbols.covers(*astral.end()));
+        if let Some(next) = char::from_u32(*astral.end() as u32 + 1) {
+            assert!(!symbols.covers(next));
+        }
+    }
+
+    #[test]
+    fn test_font_monospace() {
+        let load = |data: &'static [u8]| Font::new(Buffer::from(data), 0).unwrap();
+        let mono = load(include_bytes!("../../assets/fonts/DejaVuSansMono.ttf"));
+        let proportional = load(include_bytes!("../../assets/fonts/PTSans-Regular.ttf"));
+        assert!(mono.is_monospace());
+        assert!(!proportional.is_monospace());
+
+        // The flag only reflects the font's own declaration.
+        for font in [&mono, &proportional] {
+            let declared = font.ttf().is_monospaced();
+            assert_eq!(font.info().flags.contains(FontFlags::MONOSPACE), declared);
+        }
+
+        assert!(has_uniform_advances(mono.ttf()));
+        assert!(!has_uniform_advances(proportional.ttf()));
+
+        let size = Abs::pt(10.0);
+        let glyph = mono.ttf().glyph_index('m').unwrap().0;
+        assert_eq!(mono.advance_width(size), mono.advance(glyph).map(|em| em.at(size)));
+        assert!(proportional.advance_width(size).unwrap() < size);
+    }
+}
@@ -116,6 +116,24 @@ impl FontWeight {
     pub fn distance(self, other: Self) -> u16 {
         (self.0 as i16 - other.0 as i16).unsigned_abs()
     }
+
+    /// Whether, when searching for this weight, a font with the `other` weight
+    /// should be preferred over one that is equally far away in the opposite
+    /// direction.
+    ///
+    /// As in CSS, lighter fonts are preferred for weights below 400 and
+    /// heavier fonts for weights above 500. In between, heavier fonts are
+    /// preferred as long as they are not heavier than 500.
+    pub fn prefers(self, other: Self) -> bool {
+        let heavier = other.0 >= self.0;
+        if self.0 < 400 {
+            !heavier
+        } else if self.0 > 500 {
+            heavier
+        } else {
+            heavier == (self.0 + self.distance(other) <= 500)
+        }
+    }
 }
 
 impl Default for FontWeight {
@@ -269,6 +287,15 @@ mod tests {
         assert_eq!(d(10, 100), 90);
     }
 
+    #[test]
+    fn test_font_weight_prefers() {
+        let p = |a, b| FontWeight(a).prefers(FontWeight(b));
+        assert!(p(300, 200) && !p(300, 400));
+        assert!(p(600, 700) && !p(600, 500));
+        assert!(p(450, 500) && !p(450, 400));
+        assert!(p(500, 400) && !p(500, 600));
+    }
+
     #[test]
     fn test_font_stretch_debug() {
         assert_eq!(format!("{:?}", FontStretch::EXPANDED), "125%")
@@ -0,0 +1,193 @@
+//! Decoding of WOFF font files.
+
+use crate::diag::StrResult;
+
+/// Whether the data starts with a WOFF or WOFF2 signature.
+pub fn is_woff(data: &[u8]) -> bool {
+    data.starts_with(b"wOFF") || data.starts_with(b"wOF2")
+}
+
+/// Decode a WOFF file into a plain OpenType font file.
+///
+/// Compressed tables are inflated to at most their declared original length.
+///
+/// WOFF2 files are detected, but not supported as they require a Brotli
+/// decoder and reconstruction of transformed tables.
+pub fn decode(data: &[u8]) -> StrResult<Vec<u8>> {
+    if data.starts_with(b"wOF2") {
+        Err("WOFF2 fonts are not supported")?;
+    } else if !data.starts_with(b"wOFF") {
+        Err("font is not a WOFF file")?;
+    }
+
+    let flavor = read_u32(data, 4)?;
+    let num_tables = read_u16(data, 12)?;
+
+    // Extract and decompress the tables.
+    let mut tables = vec![];
+    for i in 0..usize::from(num_tables) {
+        let entry = 44 + 20 * i;
+        let tag = read_u32(data, entry)?;
+        let offset = read_u32(data, entry + 4)? as usize;
+        let comp_length = read_u32(data, entry + 8)? as usize;
+        let orig_length = read_u32(data, entry + 12)? as usize;
+        let checksum = read_u32(data, entry + 16)?;
+
+        let compressed = offset
+            .checked_add(comp_length)
+            .and_then(|end| data.get(offset..end))
+            .ok_or("WOFF table is out of bounds")?;
+
+        let table = if comp_length < orig_length {
+            // Limit the output so that a malicious table can't inflate
+            // without bound.
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(
+                compressed,
+                orig_length,
+            )
+            .map_err(|_| "failed to decompress WOFF table")?
+        } else if comp_length == orig_length {
+            compressed.to_vec()
+        } else {
+            Err("WOFF table is longer than its decompressed length")?
+        };
+
+        if table.len() != orig_length {
+            Err("WOFF table has the wrong decompressed length")?;
+        }
+
+        tables.push((tag, checksum, table));
+    }
+
+    // The table records must be sorted by tag.
+    tables.sort_by_key(|&(tag, _, _)| tag);
+
+    // Write the offset table.
+    let entry_selector = 15 - num_tables.max(1).leading_zeros() as u16;
+    let search_range = 16 << entry_selector;
+    let range_shift = num_tables.saturating_mul(16).saturating_sub(search_range);
+    let mut sfnt = vec![];
+    sfnt.extend(flavor.to_be_bytes());
+    sfnt.extend(num_tables.to_be_bytes());
+    sfnt.extend(search_range.to_be_bytes());
+    sfnt.extend(entry_selector.to_be_bytes());
+    sfnt.extend(range_shift.to_be_bytes());
+
+    // Write the table records.
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, checksum, table) in &tables {
+        sfnt.extend(tag.to_be_bytes());
+        sfnt.extend(checksum.to_be_bytes());
+        sfnt.extend((offset as u32).to_be_bytes());
+        sfnt.extend((table.len() as u32).to_be_bytes());
+        offset += padded(table.len());
+    }
+
+    // Write the tables, each padded to a multiple of four bytes.
+    for (_, _, table) in &tables {
+        sfnt.extend_from_slice(table);
+        sfnt.resize(padded(sfnt.len()), 0);
+    }
+
+    Ok(sfnt)
+}
+
+/// Round up to the next multiple of four.
+fn padded(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Read a big-endian `u16` at the given offset.
+fn read_u16(data: &[u8], at: usize) -> StrResult<u16> {
+    match data.get(at..at + 2) {
+        Some(&[a, b]) => Ok(u16::from_be_bytes([a, b])),
+        _ => Err("WOFF file is truncated".into()),
+    }
+}
+
+/// Read a big-endian `u32` at the given offset.
+fn read_u32(data: &[u8], at: usize) -> StrResult<u32> {
+    match data.get(at..at + 4) {
+        Some(&[a, b, c, d]) => Ok(u32::from_be_bytes([a, b, c, d])),
+        _ => Err("WOFF file is truncated".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode a font file as an uncompressed WOFF file.
+    fn encode(sfnt: &[u8]) -> Vec<u8> {
+        let num_tables = u16::from_be_bytes([sfnt[4], sfnt[5]]);
+        let mut woff = b"wOFF".to_vec();
+        woff.extend_from_slice(&sfnt[..4]);
+        woff.extend(0u32.to_be_bytes());
+        woff.extend(num_tables.to_be_bytes());
+        woff.resize(44, 0);
+
+        let mut data = vec![];
+        let data_start = 44 + 20 * usize::from(num_tables);
+        for i in 0..usize::from(num_tables) {
+            let record = &sfnt[12 + 16 * i..28 + 16 * i];
+            let offset = u32::from_be_bytes(record[8..12].try_into().unwrap()) as usize;
+            let length = u32::from_be_bytes(record[12..16].try_into().unwrap()) as usize;
+            woff.extend_from_slice(&record[..4]);
+            woff.extend(((data_start + data.len()) as u32).to_be_bytes());
+            woff.extend((length as u32).to_be_bytes());
+            woff.extend((length as u32).to_be_bytes());
+            woff.extend_from_slice(&record[4..8]);
+            data.extend_from_slice(&sfnt[offset..offset + length]);
+        }
+
+        woff.extend(data);
+        woff
+    }
+
+    #[test]
+    fn test_woff_round_trip() {
+        let sfnt = include_bytes!("../../assets/fonts/PTSans-Regular.ttf");
+        let woff = encode(sfnt);
+        assert!(is_woff(&woff));
+
+        let decoded = decode(&woff).unwrap();
+        let original = ttf_parser::Face::parse(sfnt, 0).unwrap();
+        let face = ttf_parser::Face::parse(&decoded, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), original.number_of_glyphs());
+        assert_eq!(face.glyph_index('a'), original.glyph_index('a'));
+    }
+
+    #[test]
+    fn test_woff_invalid() {
+        let woff = encode(include_bytes!("../../assets/fonts/PTSans-Regular.ttf"));
+        assert_eq!(decode(&woff[..100]), Err("WOFF table is out of bounds".into()));
+        assert_eq!(decode(&woff[..20]), Err("WOFF file is truncated".into()));
+    }
+
+    #[test]
+    fn test_woff_decompression_limit() {
+        // A table that claims to inflate to 100 bytes, but inflates to 1000.
+        let table = miniz_oxide::deflate::compress_to_vec_zlib(&[0; 1000], 6);
+        let mut woff = b"wOFF".to_vec();
+        woff.extend(0x00010000u32.to_be_bytes());
+        woff.extend(0u32.to_be_bytes());
+        woff.extend(1u16.to_be_bytes());
+        woff.resize(44, 0);
+        woff.extend(u32::from_be_bytes(*b"test").to_be_bytes());
+        woff.extend(64u32.to_be_bytes());
+        woff.extend((table.len() as u32).to_be_bytes());
+        woff.extend(100u32.to_be_bytes());
+        woff.extend(0u32.to_be_bytes());
+        woff.extend(table);
+        assert_eq!(decode(&woff), Err("failed to decompress WOFF table".into()));
+    }
+
+    #[test]
+    fn test_woff2_unsupported() {
+        let mut woff2 = b"wOF2".to_vec();
+        woff2.extend(0x00010000u32.to_be_bytes());
+        woff2.resize(48, 0);
+        assert!(is_woff(&woff2));
+        assert_eq!(decode(&woff2), Err("WOFF2 fonts are not supported".into()));
+    }
+}
@@ -1,6 +1,18 @@
+use std::str::FromStr;
+
 use super::*;
 
 /// An absolute length.
+///
+/// Lengths can be added to and subtracted from each other and scaled by
+/// unitless factors. Dividing two lengths yields a unitless factor. Multiplying
+/// two lengths, however, would yield an area and is deliberately not
+/// implemented:
+///
+/// ```compile_fail
+/// # use typst::geom::Abs;
+/// let area = Abs::pt(2.0) * Abs::pt(3.0);
+/// ```
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Abs(Scalar);
 
@@ -75,11 +87,114 @@ impl Abs {
         self.to_unit(AbsUnit::In)
     }
 
+    /// Format the value of this absolute length in a unit, without the unit.
+    ///
+    /// The value is rounded to `precision` decimal places and trailing zeros
+    /// are trimmed.
+    pub fn to_unit_string(self, unit: AbsUnit, precision: usize) -> String {
+        format_number(self.to_unit(unit), precision)
+    }
+
+    /// Format the length in points in scientific notation with the given
+    /// number of significant figures, e.g. `1.00e-4pt`.
+    ///
+    /// At least one significant figure is always printed.
+    pub fn to_scientific_string(self, sig_figs: usize) -> String {
+        format!("{:.*e}pt", sig_figs.saturating_sub(1), self.to_pt())
+    }
+
+    /// Wrap the length so that it is debug-printed in the given unit, followed
+    /// by its value in points, e.g. `3cm (85.04pt)`.
+    pub fn tagged(self, unit: AbsUnit) -> TaggedAbs {
+        TaggedAbs { abs: self, unit }
+    }
+
+    /// Format this length as an SVG length in a unit, e.g. `12pt`.
+    ///
+    /// SVG supports all absolute units (`pt`, `mm`, `cm` and `in`). The value
+    /// is rounded to four decimal places.
+    pub fn to_svg(self, unit: AbsUnit) -> String {
+        format!("{}{:?}", self.to_unit_string(unit, 4), unit)
+    }
+
+    /// Format this length as a CSS length in a unit, e.g. `12pt`.
+    ///
+    /// CSS supports all absolute units, so this is the same as
+    /// [`to_svg`](Self::to_svg). For pixels, use [`to_css_px`](Self::to_css_px).
+    pub fn to_css(self, unit: AbsUnit) -> String {
+        self.to_svg(unit)
+    }
+
+    /// Format this length as a CSS length in pixels, e.g. `16px`.
+    ///
+    /// A CSS pixel is defined as a 96th of an inch.
+    pub fn to_css_px(self) -> String {
+        format!("{}px", format_number(self / Self::css_px(), 4))
+    }
+
+    /// Parse a CSS length like `12px`, `2.5cm` or `0`.
+    ///
+    /// Supports the units `px`, `pt`, `mm`, `cm` and `in` in any case, where a
+    /// pixel is a 96th of an inch. As in CSS, zero may be written without a
+    /// unit. Percentages are rejected as they don't denote absolute lengths.
+    pub fn from_css(s: &str) -> Result<Self, ParseAbsError> {
+        if s.ends_with('%') {
+            return Err(ParseAbsError::new(
+                "percentages are not absolute lengths",
+                s.len() - 1..s.len(),
+            ));
+        }
+
+        if let Some(i) = s.find(char::is_whitespace) {
+            return Err(ParseAbsError::new(
+                "CSS lengths must not contain whitespace",
+                i..i + 1,
+            ));
+        }
+
+        if s.parse::<f64>().map_or(false, |v| v == 0.0) {
+            return Ok(Self::zero());
+        }
+
+        parse(s, |unit| match unit.to_ascii_lowercase().as_str() {
+            "px" => Some(Self::css_px()),
+            "pt" => Some(Self::pt(1.0)),
+            "mm" => Some(Self::mm(1.0)),
+            "cm" => Some(Self::cm(1.0)),
+            "in" => Some(Self::inches(1.0)),
+            _ => None,
+        })
+    }
+
+    /// The length of a CSS pixel.
+    fn css_px() -> Self {
+        Self::inches(1.0 / 96.0)
+    }
+
     /// The absolute value of this length.
     pub fn abs(self) -> Self {
         Self::raw(self.to_raw().abs())
     }
 
+    /// Round to whole points, rounding ties to the nearest even number.
+    pub fn round_ties_even(self) -> Self {
+        let pt = self.to_pt();
+        let rounded = if (pt - pt.trunc()).abs() == 0.5 {
+            2.0 * (pt / 2.0).round()
+        } else {
+            pt.round()
+        };
+        Self::pt(rounded)
+    }
+
+    /// Round to whole points, rounding ties upwards (towards positive
+    /// infinity).
+    pub fn round_ties_up(self) -> Self {
+        let pt = self.to_pt();
+        let rounded = if pt - pt.floor() == 0.5 { pt.ceil() } else { pt.round() };
+        Self::pt(rounded)
+    }
+
     /// The minimum of this and another absolute length.
     pub fn min(self, other: Self) -> Self {
         Self(self.0.min(other.0))
@@ -100,6 +215,40 @@ impl Abs {
         *self = (*self).max(other);
     }
 
+    /// Clamp a preferred length between a minimum and a maximum, like CSS's
+    /// `clamp(min, preferred, max)`.
+    ///
+    /// As in CSS, the minimum wins if it is larger than the maximum.
+    pub fn fluid(min: Self, preferred: Self, max: Self) -> Self {
+        preferred.min(max).max(min)
+    }
+
+    /// A length that grows linearly with the viewport width, clamped between a
+    /// minimum and a maximum.
+    ///
+    /// This is the common fluid typography pattern
+    /// `clamp(min, intercept + slope * 100vw, max)`.
+    pub fn fluid_vw(
+        min: Self,
+        max: Self,
+        viewport: Self,
+        slope: f64,
+        intercept: Self,
+    ) -> Self {
+        Self::fluid(min, intercept + viewport * slope, max)
+    }
+
+    /// Add another absolute length, but clamp the sum to `max`.
+    ///
+    /// Returns whether the sum hit `max`, that is, whether it reached or
+    /// exceeded it.
+    pub fn add_clamped(&mut self, other: Self, max: Self) -> bool {
+        *self += other;
+        let hit = *self >= max;
+        self.set_min(max);
+        hit
+    }
+
     /// Whether the other absolute length fits into this one (i.e. is smaller).
     /// Allows for a bit of slack.
     pub fn fits(self, other: Self) -> bool {
@@ -111,6 +260,104 @@ impl Abs {
         self == other || (self - other).to_raw().abs() < 1e-6
     }
 
+    /// Evaluate the piecewise-linear curve through the given stops at `t`.
+    ///
+    /// The stops must be sorted by their keys. Outside of the keys' range, the
+    /// curve is constant. Without any stops, the result is zero.
+    pub fn piecewise(stops: &[(f64, Self)], t: f64) -> Self {
+        debug_assert!(stops.windows(2).all(|w| w[0].0 <= w[1].0), "stops are not sorted");
+        let i = stops.partition_point(|&(key, _)| key <= t);
+        match (i.checked_sub(1).and_then(|i| stops.get(i)), stops.get(i)) {
+            (Some(&(a, start)), Some(&(b, end))) => lerp(start, end, (t - a) / (b - a)),
+            (Some(&(_, v)), None) | (None, Some(&(_, v))) => v,
+            (None, None) => Self::zero(),
+        }
+    }
+
+    /// Iterate from `start` to `end` (inclusive) in increments of `step`.
+    ///
+    /// A negative step yields descending lengths. The iterator is empty if the
+    /// step is zero or points away from `end`. To deal with floating point
+    /// imprecision, `end` itself is included if it is approximately reached.
+    pub fn steps(start: Self, end: Self, step: Self) -> impl Iterator<Item = Self> {
+        let count = (end - start) / step;
+        let count = if count.is_finite() && count >= 0.0 {
+            (count + 1e-9).floor() as usize + 1
+        } else {
+            0
+        };
+        (0..count).map(move |i| start + step * i as f64)
+    }
+
+    /// The fraction of `whole` that this length makes up, clamped to the range
+    /// from zero to one.
+    ///
+    /// Returns zero if `whole` is zero. Use division for the unclamped ratio.
+    pub fn fraction_of(self, whole: Self) -> f64 {
+        let fraction = self / whole;
+        if fraction.is_finite() {
+            fraction.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Find the largest length of at least `min` that divides both `a` and `b`
+    /// approximately evenly.
+    ///
+    /// Searches the fractions `min(a, b) / k` for increasing `k` until they
+    /// become smaller than `min`. Returns `None` if no such length is found or
+    /// any of the lengths is not positive.
+    pub fn largest_common_divisor(a: Self, b: Self, min: Self) -> Option<Self> {
+        if a <= Self::zero() || b <= Self::zero() || min <= Self::zero() {
+            return None;
+        }
+
+        let is_whole = |v: f64| (v - v.round()).abs() < 1e-6;
+        let smaller = a.min(b);
+        (1..)
+            .map(|k| smaller / k as f64)
+            .take_while(|&d| d.fits(min))
+            .find(|&d| is_whole(a / d) && is_whole(b / d))
+    }
+
+    /// Split `total` into `count` equally wide columns separated by gutters.
+    ///
+    /// Returns `None` if there are no columns or the gutters don't fit into
+    /// the total width.
+    pub fn columns(total: Self, count: usize, gutter: Self) -> Option<Vec<Self>> {
+        let gutters = gutter * count.checked_sub(1)? as f64;
+        if !total.fits(gutters) {
+            return None;
+        }
+
+        let width = ((total - gutters) / count as f64).max(Self::zero());
+        Some(vec![width; count])
+    }
+
+    /// Snap to the nearest guide that is at most `threshold` away or keep this
+    /// length if there is none.
+    ///
+    /// If two guides are equally near, the first one is picked.
+    pub fn snap_to(self, guides: &[Self], threshold: Self) -> Self {
+        let mut nearest = None;
+        for &guide in guides {
+            let distance = (guide - self).abs();
+            if threshold.fits(distance) && nearest.map_or(true, |(d, _)| distance < d) {
+                nearest = Some((distance, guide));
+            }
+        }
+        nearest.map_or(self, |(_, guide)| guide)
+    }
+
+    /// Divide by a number, failing if the divisor is zero or not finite.
+    pub fn try_div(self, divisor: f64) -> Result<Self, DivByZero> {
+        if divisor == 0.0 || !divisor.is_finite() {
+            return Err(DivByZero);
+        }
+        Ok(self / divisor)
+    }
+
     /// Perform a checked division by a number, returning zero if the result
     /// is not finite.
     pub fn safe_div(self, number: f64) -> Self {
@@ -133,12 +380,69 @@ impl Numeric for Abs {
     }
 }
 
+/// Lengths can be summed up and thus have an additive identity. `One` is not
+/// implemented since the product of two lengths is not a length.
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Abs {
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<Abs> for euclid::Length<f32, U> {
+    fn from(abs: Abs) -> Self {
+        euclid::Length::new(abs.to_pt() as f32)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<euclid::Length<f32, U>> for Abs {
+    fn from(length: euclid::Length<f32, U>) -> Self {
+        Self::pt(length.get().into())
+    }
+}
+
 impl Debug for Abs {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{}pt", round_2(self.to_pt()))
     }
 }
 
+impl fmt::Display for Abs {
+    /// Formats the length in points, honoring the formatter's precision,
+    /// width, fill and alignment. Without a precision, the length is rounded
+    /// to two decimal places.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match f.precision() {
+            Some(precision) => format!("{:.*}pt", precision, self.to_pt()),
+            None => format!("{}pt", round_2(self.to_pt())),
+        };
+
+        // Like numbers, lengths are right-aligned by default.
+        let padding = f.width().unwrap_or(0).saturating_sub(string.chars().count());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            write!(f, "{fill}")?;
+        }
+        f.write_str(&string)?;
+        for _ in 0..after {
+            write!(f, "{fill}")?;
+        }
+        Ok(())
+    }
+}
+
 impl Neg for Abs {
     type Output = Self;
 
@@ -173,6 +477,22 @@ impl Mul<Abs> for f64 {
     }
 }
 
+impl Mul<Ratio> for Abs {
+    type Output = Self;
+
+    fn mul(self, other: Ratio) -> Self {
+        self * other.get()
+    }
+}
+
+impl Mul<Abs> for Ratio {
+    type Output = Abs;
+
+    fn mul(self, other: Abs) -> Abs {
+        other * self
+    }
+}
+
 impl Div<f64> for Abs {
     type Output = Self;
 
@@ -185,13 +505,14 @@ impl Div for Abs {
     type Output = f64;
 
     fn div(self, other: Self) -> f64 {
-        self.to_raw() / other.to_raw()
+        Scalar::checked(self.to_raw(), other.to_raw(), |a, b| a / b).0
     }
 }
 
 assign_impl!(Abs += Abs);
 assign_impl!(Abs -= Abs);
 assign_impl!(Abs *= f64);
+assign_impl!(Abs *= Ratio);
 assign_impl!(Abs /= f64);
 
 impl Rem for Abs {
@@ -214,6 +535,129 @@ impl<'a> Sum<&'a Self> for Abs {
     }
 }
 
+impl FromStr for Abs {
+    type Err = ParseAbsError;
+
+    /// Parses an absolute length from strings like the following:
+    /// - `12pt`,
+    /// - `+2.5cm` (with leading sign),
+    /// - `1.2e1mm` (in scientific notation),
+    /// - `12 PT` (with whitespace and in any case).
+    ///
+    /// Trailing whitespace is ignored. The unit is required and must be one of
+    /// the following (case-insensitively):
+    /// - `pt`, `point` or `points`,
+    /// - `mm`, `millimeter` or `millimeters`,
+    /// - `cm`, `centimeter` or `centimeters`,
+    /// - `in`, `inch` or `inches`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s.trim_end(), |unit| match unit.to_ascii_lowercase().as_str() {
+            "pt" | "point" | "points" => Some(AbsUnit::Pt),
+            "mm" | "millimeter" | "millimeters" => Some(AbsUnit::Mm),
+            "cm" | "centimeter" | "centimeters" => Some(AbsUnit::Cm),
+            "in" | "inch" | "inches" => Some(AbsUnit::In),
+            _ => None,
+        })
+    }
+}
+
+/// Format a number with at most `precision` decimal places, without trailing
+/// zeros.
+fn format_number(value: f64, precision: usize) -> String {
+    let mut string = format!("{:.*}", precision, value);
+    if string.contains('.') {
+        let trimmed = string.trim_end_matches('0').trim_end_matches('.').len();
+        string.truncate(trimmed);
+    }
+    if string == "-0" {
+        string.remove(0);
+    }
+    string
+}
+
+/// Parse a number followed by a unit, which is resolved to the length of one
+/// such unit with `unit`. The number and the unit may be separated by
+/// whitespace.
+fn parse<F, U>(s: &str, unit: F) -> Result<Abs, ParseAbsError>
+where
+    F: FnOnce(&str) -> Option<U>,
+    U: Into<Abs>,
+{
+    let split = s.trim_end_matches(|c: char| c.is_ascii_alphabetic()).len();
+    let (number, suffix) = s.split_at(split);
+    if suffix.is_empty() {
+        return Err(ParseAbsError::new("length is missing a unit", s.len()..s.len()));
+    }
+
+    let Some(unit) = unit(suffix) else {
+        return Err(ParseAbsError::new("length has an unknown unit", split..s.len()));
+    };
+
+    match number.trim_end().parse::<f64>() {
+        Ok(val) if val.is_finite() => Ok(unit.into() * val),
+        _ => Err(ParseAbsError::new("length has an invalid number", 0..split)),
+    }
+}
+
+/// An absolute length tagged with the unit it should be printed in.
+///
+/// Created with [`Abs::tagged`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TaggedAbs {
+    /// The length.
+    pub abs: Abs,
+    /// The unit to print the length in.
+    pub unit: AbsUnit,
+}
+
+impl Debug for TaggedAbs {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{:?} ({:?})",
+            self.abs.to_unit_string(self.unit, 2),
+            self.unit,
+            self.abs
+        )
+    }
+}
+
+/// An error that occurred while parsing an absolute length from a string.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseAbsError {
+    /// A description of what went wrong.
+    pub message: &'static str,
+    /// The byte range in the input string at which the problem lies.
+    pub range: Range<usize>,
+}
+
+impl ParseAbsError {
+    /// Create a new parse error.
+    fn new(message: &'static str, range: Range<usize>) -> Self {
+        Self { message, range }
+    }
+}
+
+impl fmt::Display for ParseAbsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.message)
+    }
+}
+
+impl std::error::Error for ParseAbsError {}
+
+/// An error that occurred when dividing by zero or a non-finite number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DivByZero;
+
+impl fmt::Display for DivByZero {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad("cannot divide by zero")
+    }
+}
+
+impl std::error::Error for DivByZero {}
+
 cast_to_value! {
     v: Abs => Value::Length(v.into())
 }
@@ -243,6 +687,12 @@ impl AbsUnit {
     }
 }
 
+impl From<AbsUnit> for Abs {
+    fn from(unit: AbsUnit) -> Self {
+        Self::with_unit(1.0, unit)
+    }
+}
+
 impl Debug for AbsUnit {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.pad(match self {
@@ -262,4 +712,231 @@ mod tests {
     fn test_length_unit_conversion() {
         assert!((Abs::mm(150.0).to_cm() - 15.0) < 1e-4);
     }
+
+    #[test]
+    fn test_length_unit_string() {
+        assert_eq!(Abs::cm(2.5).to_unit_string(AbsUnit::Cm, 2), "2.5");
+        assert_eq!(Abs::pt(12.0).to_unit_string(AbsUnit::Pt, 3), "12");
+        assert_eq!(Abs::inches(1.0).to_unit_string(AbsUnit::Pt, 0), "72");
+        assert_eq!(Abs::pt(-0.001).to_unit_string(AbsUnit::Pt, 2), "0");
+    }
+
+    #[test]
+    fn test_length_scientific_string() {
+        assert_eq!(Abs::pt(0.0001).to_scientific_string(3), "1.00e-4pt");
+        assert_eq!(Abs::pt(-1234.5).to_scientific_string(2), "-1.2e3pt");
+        assert_eq!(Abs::pt(0.0).to_scientific_string(0), "0e0pt");
+    }
+
+    #[test]
+    fn test_length_to_svg() {
+        assert_eq!(Abs::inches(1.0).to_svg(AbsUnit::In), "1in");
+        assert_eq!(Abs::pt(12.5).to_svg(AbsUnit::Pt), "12.5pt");
+        assert_eq!(Abs::cm(1.0).to_svg(AbsUnit::Mm), "10mm");
+    }
+
+    #[test]
+    fn test_length_css_round_trip() {
+        let inch = Abs::inches(1.0);
+        assert!(Abs::from_css("1in").unwrap().approx_eq(inch));
+        assert!(Abs::from_css("96px").unwrap().approx_eq(inch));
+        assert!(Abs::from_css("96PX").unwrap().approx_eq(inch));
+        assert_eq!(Abs::from_css("0"), Ok(Abs::zero()));
+        assert_eq!(inch.to_css_px(), "96px");
+        assert_eq!(inch.to_css(AbsUnit::In), "1in");
+        assert!(Abs::from_css(&inch.to_css_px()).unwrap().approx_eq(inch));
+        assert_eq!(Abs::from_css("50%").map_err(|err| err.range), Err(2..3));
+        assert_eq!(Abs::from_css("1 in").map_err(|err| err.range), Err(1..2));
+    }
+
+    #[test]
+    fn test_length_add_clamped() {
+        let max = Abs::pt(10.0);
+        let mut width = Abs::pt(4.0);
+        assert!(!width.add_clamped(Abs::pt(5.0), max));
+        assert_eq!(width, Abs::pt(9.0));
+        assert!(width.add_clamped(Abs::pt(1.0), max));
+        assert_eq!(width, max);
+        assert!(width.add_clamped(Abs::pt(1.0), max));
+        assert_eq!(width, max);
+    }
+
+    #[test]
+    fn test_length_fluid() {
+        let (min, max) = (Abs::pt(12.0), Abs::pt(20.0));
+        assert_eq!(Abs::fluid(min, Abs::pt(16.0), max), Abs::pt(16.0));
+        assert_eq!(Abs::fluid(min, Abs::pt(8.0), max), min);
+        assert_eq!(Abs::fluid(min, Abs::pt(30.0), max), max);
+        assert_eq!(Abs::fluid(max, Abs::pt(16.0), min), max);
+
+        let fluid =
+            |viewport| Abs::fluid_vw(min, max, Abs::pt(viewport), 0.02, Abs::pt(8.0));
+        assert_eq!(fluid(200.0), min);
+        assert!(fluid(400.0).approx_eq(Abs::pt(16.0)));
+        assert!(fluid(500.0).approx_eq(Abs::pt(18.0)));
+        assert_eq!(fluid(1000.0), max);
+    }
+
+    #[test]
+    fn test_length_piecewise() {
+        let stops = [(0.0, Abs::pt(10.0)), (1.0, Abs::pt(20.0)), (2.0, Abs::pt(0.0))];
+        assert_eq!(Abs::piecewise(&stops[..2], 0.5), Abs::pt(15.0));
+        assert_eq!(Abs::piecewise(&stops, 1.5), Abs::pt(10.0));
+        assert_eq!(Abs::piecewise(&stops, 1.0), Abs::pt(20.0));
+        assert_eq!(Abs::piecewise(&stops, -1.0), Abs::pt(10.0));
+        assert_eq!(Abs::piecewise(&stops, 3.0), Abs::zero());
+        assert_eq!(Abs::piecewise(&[], 0.5), Abs::zero());
+    }
+
+    #[test]
+    fn test_length_steps() {
+        let ticks: Vec<_> =
+            Abs::steps(Abs::zero(), Abs::pt(10.0), Abs::pt(2.0)).collect();
+        assert_eq!(ticks.len(), 6);
+        assert_eq!(ticks.last(), Some(&Abs::pt(10.0)));
+        assert_eq!(Abs::steps(Abs::pt(1.0), Abs::zero(), Abs::pt(-0.5)).count(), 3);
+        assert_eq!(Abs::steps(Abs::zero(), Abs::pt(1.0), Abs::pt(0.1)).count(), 11);
+        assert_eq!(Abs::steps(Abs::zero(), Abs::pt(1.0), Abs::zero()).count(), 0);
+        assert_eq!(Abs::steps(Abs::zero(), Abs::pt(1.0), Abs::pt(-1.0)).count(), 0);
+    }
+
+    #[test]
+    fn test_length_fraction_of() {
+        assert_eq!(Abs::pt(5.0).fraction_of(Abs::pt(10.0)), 0.5);
+        assert_eq!(Abs::pt(15.0).fraction_of(Abs::pt(10.0)), 1.0);
+        assert_eq!(Abs::pt(-5.0).fraction_of(Abs::pt(10.0)), 0.0);
+        assert_eq!(Abs::pt(5.0).fraction_of(Abs::zero()), 0.0);
+    }
+
+    #[test]
+    fn test_length_largest_common_divisor() {
+        let lcd = Abs::largest_common_divisor;
+        assert_eq!(lcd(Abs::pt(12.0), Abs::pt(8.0), Abs::pt(1.0)), Some(Abs::pt(4.0)));
+        assert_eq!(lcd(Abs::pt(12.0), Abs::pt(12.0), Abs::pt(1.0)), Some(Abs::pt(12.0)));
+        assert_eq!(lcd(Abs::pt(12.0), Abs::pt(7.0), Abs::pt(2.0)), None);
+        assert_eq!(lcd(Abs::pt(12.0), Abs::pt(8.0), Abs::zero()), None);
+    }
+
+    #[test]
+    fn test_length_columns() {
+        let columns = Abs::columns(Abs::pt(100.0), 3, Abs::pt(10.0)).unwrap();
+        assert_eq!(columns.len(), 3);
+        assert!(columns.iter().all(|w| w.approx_eq(Abs::pt(80.0 / 3.0))));
+        assert_eq!(
+            Abs::columns(Abs::pt(10.0), 1, Abs::pt(20.0)),
+            Some(vec![Abs::pt(10.0)])
+        );
+        assert_eq!(Abs::columns(Abs::pt(10.0), 3, Abs::pt(6.0)), None);
+        assert_eq!(Abs::columns(Abs::pt(10.0), 0, Abs::zero()), None);
+    }
+
+    #[test]
+    fn test_length_snap_to() {
+        let guides = [Abs::zero(), Abs::pt(10.0), Abs::pt(20.0)];
+        assert_eq!(Abs::pt(9.0).snap_to(&guides, Abs::pt(2.0)), Abs::pt(10.0));
+        assert_eq!(Abs::pt(15.0).snap_to(&guides, Abs::pt(2.0)), Abs::pt(15.0));
+        assert_eq!(Abs::pt(15.0).snap_to(&guides, Abs::pt(5.0)), Abs::pt(10.0));
+    }
+
+    #[test]
+    fn test_length_tagged_debug() {
+        assert_eq!(format!("{:?}", Abs::cm(3.0).tagged(AbsUnit::Cm)), "3cm (85.04pt)");
+        assert_eq!(format!("{:?}", Abs::pt(12.0).tagged(AbsUnit::Pt)), "12pt (12pt)");
+    }
+
+    #[test]
+    fn test_length_rounding_ties() {
+        let even = |pt| Abs::pt(pt).round_ties_even().to_pt();
+        let up = |pt| Abs::pt(pt).round_ties_up().to_pt();
+        assert_eq!([even(0.5), even(1.5), even(2.5), even(-1.5)], [0.0, 2.0, 2.0, -2.0]);
+        assert_eq!([up(0.5), up(1.5), up(2.5), up(-1.5)], [1.0, 2.0, 3.0, -1.0]);
+        assert_eq!([even(1.4), up(1.6)], [1.0, 2.0]);
+
+        // Values just below a tie and values without a fractional part must
+        // not be rounded up.
+        let below = 0.49999999999999994;
+        let large = 4503599627370497.0;
+        assert_eq!([even(below), up(below), up(-below)], [0.0, 0.0, -0.0]);
+        assert_eq!([even(large), up(large), up(-large)], [large, large, -large]);
+    }
+
+    #[test]
+    fn test_length_display() {
+        assert_eq!(format!("{}", Abs::pt(12.0)), "12pt");
+        assert_eq!(format!("{:>8}", Abs::pt(12.0)), "    12pt");
+        assert_eq!(format!("{:<8}|", Abs::pt(12.0)), "12pt    |");
+        assert_eq!(format!("{:*^9.1}", Abs::pt(1.26)), "**1.3pt**");
+        assert_eq!(format!("{:8.3}", Abs::pt(1.5)), " 1.500pt");
+    }
+
+    #[test]
+    fn test_length_try_div() {
+        assert_eq!(Abs::pt(10.0).try_div(2.0), Ok(Abs::pt(5.0)));
+        assert_eq!(Abs::pt(10.0).try_div(0.0), Err(DivByZero));
+        assert_eq!(Abs::pt(10.0).try_div(f64::NAN), Err(DivByZero));
+    }
+
+    #[test]
+    fn test_length_ratio_multiplication() {
+        assert_eq!(Abs::pt(10.0) * Ratio::new(0.5), Abs::pt(5.0));
+        assert_eq!(Ratio::new(0.5) * Abs::pt(10.0), Abs::pt(5.0));
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn test_length_num_traits_zero() {
+        assert_eq!(<Abs as num_traits::Zero>::zero(), Abs::zero());
+        assert!(num_traits::Zero::is_zero(&Abs::zero()));
+        assert!(!num_traits::Zero::is_zero(&Abs::pt(1.0)));
+    }
+
+    #[test]
+    #[cfg(feature = "euclid")]
+    fn test_length_euclid_round_trip() {
+        let length: euclid::Length<f32, ()> = Abs::pt(12.5).into();
+        assert_eq!(length.get(), 12.5);
+        assert_eq!(Abs::from(length), Abs::pt(12.5));
+    }
+
+    #[test]
+    fn test_parse_length_strings() {
+        #[track_caller]
+        fn test(s: &str, pt: f64) {
+            assert!(Abs::from_str(s).unwrap().approx_eq(Abs::pt(pt)));
+        }
+
+        test("12pt", 12.0);
+        test("+12pt", 12.0);
+        test("-3pt", -3.0);
+        test("1.2e1pt", 12.0);
+        test("1E-1pt", 0.1);
+        test("1in", 72.0);
+        test("2.5cm", 70.86625);
+        test("12 pt", 12.0);
+        test("12PT", 12.0);
+        test("12 Pt ", 12.0);
+        test("12points", 12.0);
+        test("1 point", 1.0);
+        test("1 inch", 72.0);
+        test("2 Inches", 144.0);
+        test("10 millimeters", 28.3465);
+        test("1 centimeter", 28.3465);
+    }
+
+    #[test]
+    fn test_parse_invalid_lengths() {
+        #[track_caller]
+        fn test(s: &str, message: &'static str, range: Range<usize>) {
+            assert_eq!(Abs::from_str(s), Err(ParseAbsError { message, range }));
+        }
+
+        test("1.2e1", "length is missing a unit", 5..5);
+        test("12", "length is missing a unit", 2..2);
+        test("12px", "length has an unknown unit", 2..4);
+        test("12xq", "length has an unknown unit", 2..4);
+        test("pt", "length has an invalid number", 0..0);
+        test("1.2.3pt", "length has an invalid number", 0..5);
+        test("12 pts", "length has an unknown unit", 3..6);
+        test(" pt", "length has an invalid number", 0..1);
+    }
 }
@@ -0,0 +1,125 @@
+use super::*;
+
+/// An area, measured in square points.
+///
+/// This is the result of multiplying two lengths, which is why it is kept
+/// separate from [`Abs`].
+#[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Area(Scalar);
+
+impl Area {
+    /// The zero area.
+    pub const fn zero() -> Self {
+        Self(Scalar(0.0))
+    }
+
+    /// Create an area from a number of square points.
+    pub const fn pt2(pt2: f64) -> Self {
+        Self(Scalar(pt2))
+    }
+
+    /// Get the value of this area in square points.
+    pub fn to_pt2(self) -> f64 {
+        (self.0).0
+    }
+
+    /// The side length of a square with this area.
+    ///
+    /// Negative areas, as produced by dot products, yield `NaN`.
+    pub fn sqrt(self) -> Abs {
+        Abs::pt(self.to_pt2().sqrt())
+    }
+}
+
+impl Numeric for Area {
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+impl Debug for Area {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}pt²", round_2(self.to_pt2()))
+    }
+}
+
+impl Neg for Area {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Add for Area {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+sub_impl!(Area - Area -> Area);
+
+impl Mul<f64> for Area {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self {
+        Self(self.0 * other)
+    }
+}
+
+impl Div<f64> for Area {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self {
+        Self(self.0 / other)
+    }
+}
+
+impl Div<Abs> for Area {
+    type Output = Abs;
+
+    fn div(self, other: Abs) -> Abs {
+        Abs::pt(self.to_pt2() / other.to_pt())
+    }
+}
+
+assign_impl!(Area += Area);
+assign_impl!(Area -= Area);
+assign_impl!(Area *= f64);
+assign_impl!(Area /= f64);
+
+impl Sum for Area {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|s| s.0).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_sqrt() {
+        let square = Size::splat(Abs::pt(3.0));
+        assert_eq!(square.area(), Area::pt2(9.0));
+        assert_eq!(square.area().sqrt(), Abs::pt(3.0));
+        assert_eq!(Size::new(Abs::pt(2.0), Abs::pt(8.0)).area().sqrt(), Abs::pt(4.0));
+    }
+
+    #[test]
+    fn test_area_of_points() {
+        let a = Point::new(Abs::pt(3.0), Abs::pt(4.0));
+        let b = Point::new(Abs::pt(-4.0), Abs::pt(3.0));
+        assert_eq!(a.length_squared(), Area::pt2(25.0));
+        assert_eq!(a.length_squared().sqrt(), a.hypot());
+        assert_eq!(a.dot(b), Area::zero());
+        assert_eq!(a.dot(a), a.length_squared());
+        assert_eq!(Area::pt2(12.0) / Abs::pt(4.0), Abs::pt(3.0));
+    }
+}
@@ -81,6 +81,17 @@ impl<T> Axes<T> {
             y: if mask.y { Some(self.y) } else { None },
         }
     }
+
+    /// Expand into sides, using `x` for the left and right and `y` for the
+    /// top and bottom side.
+    ///
+    /// This is the inverse of [`Sides::sum_by_axis`] up to a factor of two.
+    pub fn to_symmetric_sides(self) -> Sides<T>
+    where
+        T: Clone,
+    {
+        Sides::new(self.x.clone(), self.y.clone(), self.x, self.y)
+    }
 }
 
 impl<T: Default> Axes<T> {
@@ -117,6 +128,42 @@ impl<T: Ord> Axes<T> {
     }
 }
 
+impl<T: PartialOrd> Axes<T> {
+    /// Whether each component is less than the respective one in `other`.
+    pub fn lt_mask(&self, other: &Self) -> Axes<bool> {
+        Axes { x: self.x < other.x, y: self.y < other.y }
+    }
+
+    /// Whether each component is less than or equal to the respective one in
+    /// `other`.
+    pub fn le_mask(&self, other: &Self) -> Axes<bool> {
+        Axes { x: self.x <= other.x, y: self.y <= other.y }
+    }
+
+    /// Whether each component is greater than the respective one in `other`.
+    pub fn gt_mask(&self, other: &Self) -> Axes<bool> {
+        Axes { x: self.x > other.x, y: self.y > other.y }
+    }
+
+    /// Whether each component is greater than or equal to the respective one
+    /// in `other`.
+    pub fn ge_mask(&self, other: &Self) -> Axes<bool> {
+        Axes { x: self.x >= other.x, y: self.y >= other.y }
+    }
+}
+
+impl<T: Numeric> Axes<T> {
+    /// Scale each component by the respective unitless factor.
+    pub fn component_mul(self, factors: Axes<f64>) -> Self {
+        Axes { x: self.x * factors.x, y: self.y * factors.y }
+    }
+
+    /// Divide each component by the respective unitless factor.
+    pub fn component_div(self, factors: Axes<f64>) -> Self {
+        Axes { x: self.x / factors.x, y: self.y / factors.y }
+    }
+}
+
 impl<T> Get<Axis> for Axes<T> {
     type Component = T;
 
@@ -307,3 +354,37 @@ impl<T: Fold> Fold for Axes<Option<T>> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axes_comparison_masks() {
+        let a = Size::new(Abs::pt(1.0), Abs::pt(5.0));
+        let b = Size::new(Abs::pt(2.0), Abs::pt(5.0));
+        assert_eq!(a.lt_mask(&b), Axes::new(true, false));
+        assert_eq!(a.le_mask(&b), Axes::new(true, true));
+        assert_eq!(a.gt_mask(&b), Axes::new(false, false));
+        assert_eq!(a.ge_mask(&b), Axes::new(false, true));
+    }
+
+    #[test]
+    fn test_axes_component_arithmetic() {
+        let size = Size::new(Abs::pt(2.0), Abs::pt(3.0));
+        let factors = Axes::new(2.0, 4.0);
+        assert_eq!(size.component_mul(factors), Size::new(Abs::pt(4.0), Abs::pt(12.0)));
+        assert_eq!(size.component_mul(factors).component_div(factors), size);
+    }
+
+    #[test]
+    fn test_axes_to_symmetric_sides() {
+        let inset = Size::new(Abs::pt(2.0), Abs::pt(3.0));
+        let sides = inset.to_symmetric_sides();
+        assert_eq!(
+            sides,
+            Sides::new(Abs::pt(2.0), Abs::pt(3.0), Abs::pt(2.0), Abs::pt(3.0))
+        );
+        assert_eq!(sides.sum_by_axis(), Size::new(Abs::pt(4.0), Abs::pt(6.0)));
+    }
+}
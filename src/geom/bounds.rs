@@ -0,0 +1,101 @@
+use super::*;
+
+/// Accumulates the bounding box of a number of points and rectangles.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    /// The top-left and bottom-right corner, if anything was added yet.
+    corners: Option<(Point, Point)>,
+}
+
+impl Bounds {
+    /// Create empty bounds that contain nothing.
+    pub const fn empty() -> Self {
+        Self { corners: None }
+    }
+
+    /// Whether nothing was added to the bounds yet.
+    pub fn is_empty(&self) -> bool {
+        self.corners.is_none()
+    }
+
+    /// Grow the bounds to include the point.
+    pub fn extend(&mut self, point: Point) {
+        self.corners = Some(match self.corners {
+            Some((min, max)) => (min.min(point), max.max(point)),
+            None => (point, point),
+        });
+    }
+
+    /// Grow the bounds to include the whole rectangle.
+    pub fn extend_rect(&mut self, rect: Rect) {
+        self.extend(rect.min());
+        self.extend(rect.max());
+    }
+
+    /// The smallest rectangle containing everything added so far or `None` if
+    /// the bounds are empty.
+    pub fn rect(&self) -> Option<Rect> {
+        self.corners.map(|(min, max)| Rect::new(min, (max - min).to_size()))
+    }
+}
+
+impl Debug for Bounds {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.rect() {
+            Some(rect) => write!(f, "Bounds({:?}, {:?})", rect.origin, rect.size),
+            None => f.pad("Bounds(empty)"),
+        }
+    }
+}
+
+impl Extend<Point> for Bounds {
+    fn extend<T: IntoIterator<Item = Point>>(&mut self, iter: T) {
+        for point in iter {
+            Bounds::extend(self, point);
+        }
+    }
+}
+
+impl FromIterator<Point> for Bounds {
+    fn from_iter<T: IntoIterator<Item = Point>>(iter: T) -> Self {
+        let mut bounds = Self::empty();
+        Extend::extend(&mut bounds, iter);
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_extend() {
+        let pt = |x, y| Point::new(Abs::pt(x), Abs::pt(y));
+        let mut bounds = Bounds::empty();
+        assert_eq!(bounds.rect(), None);
+
+        bounds.extend(pt(2.0, 3.0));
+        assert_eq!(bounds.rect(), Some(Rect::new(pt(2.0, 3.0), Size::zero())));
+
+        Extend::extend(&mut bounds, [pt(-1.0, 5.0), pt(4.0, 0.0)]);
+        bounds.extend_rect(Rect::new(pt(3.0, 4.0), Size::splat(Abs::pt(2.0))));
+        assert_eq!(
+            bounds.rect(),
+            Some(Rect::new(pt(-1.0, 0.0), Size::new(Abs::pt(6.0), Abs::pt(6.0)))),
+        );
+    }
+
+    #[test]
+    fn test_bounds_collect() {
+        let points = vec![
+            Point::new(Abs::pt(1.0), Abs::pt(4.0)),
+            Point::new(Abs::pt(3.0), Abs::pt(-2.0)),
+            Point::new(Abs::pt(-1.0), Abs::pt(0.0)),
+        ];
+        let min = points.iter().fold(points[0], |acc, &p| acc.min(p));
+        let max = points.iter().fold(points[0], |acc, &p| acc.max(p));
+        let bounds: Bounds = points.into_iter().collect();
+        assert_eq!(bounds.rect(), Some(Rect::new(min, (max - min).to_size())));
+        assert!(std::iter::empty::<Point>().collect::<Bounds>().is_empty());
+    }
+}
@@ -5,7 +5,9 @@ mod macros;
 mod abs;
 mod align;
 mod angle;
+mod area;
 mod axes;
+mod bounds;
 mod corners;
 mod dir;
 mod ellipse;
@@ -16,6 +18,7 @@ mod paint;
 mod path;
 mod point;
 mod ratio;
+mod rect;
 mod rel;
 mod rounded;
 mod scalar;
@@ -25,11 +28,14 @@ mod size;
 mod smart;
 mod stroke;
 mod transform;
+mod translation;
 
 pub use self::abs::*;
 pub use self::align::*;
 pub use self::angle::*;
+pub use self::area::*;
 pub use self::axes::*;
+pub use self::bounds::*;
 pub use self::corners::*;
 pub use self::dir::*;
 pub use self::ellipse::*;
@@ -40,6 +46,7 @@ pub use self::paint::*;
 pub use self::path::*;
 pub use self::point::*;
 pub use self::ratio::*;
+pub use self::rect::*;
 pub use self::rel::*;
 pub use self::rounded::*;
 pub use self::scalar::*;
@@ -49,6 +56,7 @@ pub use self::size::*;
 pub use self::smart::*;
 pub use self::stroke::*;
 pub use self::transform::*;
+pub use self::translation::*;
 
 use std::cmp::Ordering;
 use std::f64::consts::PI;
@@ -111,7 +119,35 @@ pub trait Numeric:
     fn is_finite(self) -> bool;
 }
 
+impl Numeric for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+/// Linearly interpolate between `a` and `b`, where a `t` of zero yields `a`
+/// and a `t` of one yields `b`.
+pub fn lerp<T: Numeric>(a: T, b: T, t: f64) -> T {
+    a + (b - a) * t
+}
+
 /// Round a float to two decimal places.
 pub fn round_2(value: f64) -> f64 {
     (value * 100.0).round() / 100.0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(lerp(Abs::pt(10.0), Abs::pt(20.0), 0.25), Abs::pt(12.5));
+        assert_eq!(lerp(2.0, 4.0, 0.5), 3.0);
+        assert_eq!(lerp(Em::new(1.0), Em::new(-1.0), 1.0), Em::new(-1.0));
+    }
+}
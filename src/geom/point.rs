@@ -45,6 +45,87 @@ impl Point {
         Self { x: self.x.max(other.x), y: self.y.max(other.y) }
     }
 
+    /// The distance between this point and the origin.
+    pub fn hypot(self) -> Abs {
+        Abs::raw(self.x.to_raw().hypot(self.y.to_raw()))
+    }
+
+    /// The squared distance between this point and the origin.
+    pub fn length_squared(self) -> Area {
+        self.dot(self)
+    }
+
+    /// The dot product of the vectors from the origin to this and another
+    /// point.
+    pub fn dot(self, other: Self) -> Area {
+        Area::pt2(self.x.to_pt() * other.x.to_pt() + self.y.to_pt() * other.y.to_pt())
+    }
+
+    /// The distance between this point and the origin when only moving
+    /// along the axes, that is, the sum of the absolute coordinates.
+    pub fn manhattan(self) -> Abs {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// The distance between this point and the origin when diagonal steps
+    /// count as much as straight ones, that is, the larger absolute coordinate.
+    pub fn chebyshev(self) -> Abs {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// The angle between the positive x-axis and the vector from the origin
+    /// to this point.
+    ///
+    /// Since the y-axis points downwards, positive angles turn clockwise. The
+    /// angle of the origin itself is zero.
+    pub fn angle(self) -> Angle {
+        Angle::rad(self.y.to_raw().atan2(self.x.to_raw()))
+    }
+
+    /// The signed angle by which the vector to this point must be turned to
+    /// point in the same direction as the vector to `other`.
+    ///
+    /// Computed from the cross and dot product, which is numerically more
+    /// stable than the arc cosine. As for [`angle`](Self::angle), positive
+    /// angles turn clockwise. If either vector is zero, the angle is zero.
+    pub fn angle_between(self, other: Self) -> Angle {
+        let (ax, ay) = (self.x.to_raw(), self.y.to_raw());
+        let (bx, by) = (other.x.to_raw(), other.y.to_raw());
+        Angle::rad((ax * by - ay * bx).atan2(ax * bx + ay * by))
+    }
+
+    /// The polar coordinates of this point, that is, its distance from and its
+    /// angle around the origin.
+    pub fn polar(self) -> (Abs, Angle) {
+        (self.hypot(), self.angle())
+    }
+
+    /// Mirror the point across the y-axis by negating its x coordinate.
+    pub fn reflect_x(self) -> Self {
+        Self::new(-self.x, self.y)
+    }
+
+    /// Mirror the point across the x-axis by negating its y coordinate.
+    pub fn reflect_y(self) -> Self {
+        Self::new(self.x, -self.y)
+    }
+
+    /// Mirror the point through the origin by negating both coordinates.
+    pub fn reflect_origin(self) -> Self {
+        -self
+    }
+
+    /// Snap each coordinate independently to the nearest guide for its axis
+    /// that is at most `threshold` away.
+    ///
+    /// See [`Abs::snap_to`] for details.
+    pub fn snap_to(self, x_guides: &[Abs], y_guides: &[Abs], threshold: Abs) -> Self {
+        Self::new(
+            self.x.snap_to(x_guides, threshold),
+            self.y.snap_to(y_guides, threshold),
+        )
+    }
+
     /// Transform the point with the given transformation.
     pub fn transform(self, ts: Transform) -> Self {
         Self::new(
@@ -53,6 +134,33 @@ impl Point {
         )
     }
 
+    /// Move the point by the given translation.
+    pub fn translate(self, by: Translation) -> Self {
+        self + by.0
+    }
+
+    /// Iterate over the top-left corners of the cells in a grid, in row-major
+    /// order.
+    ///
+    /// The grid starts at `origin` and spans `extent`. Only cells that fit
+    /// completely are included, partial cells at the trailing edges are not.
+    /// The iterator is empty if the cell size is not positive.
+    pub fn grid(origin: Self, extent: Size, cell: Size) -> impl Iterator<Item = Self> {
+        let xs = move || Abs::steps(origin.x, origin.x + extent.x - cell.x, cell.x);
+        let ys = Abs::steps(origin.y, origin.y + extent.y - cell.y, cell.y);
+        let positive = cell.x > Abs::zero() && cell.y > Abs::zero();
+        ys.filter(move |_| positive)
+            .flat_map(move |y| xs().map(move |x| Self::new(x, y)))
+    }
+
+    /// Convert to PDF user space on a page with the given height.
+    ///
+    /// PDF user space measures in points like this crate does, but has its
+    /// origin in the bottom-left corner with the y-axis pointing upwards.
+    pub fn to_pdf(self, page_height: Abs) -> Self {
+        Self::new(self.x, page_height - self.y)
+    }
+
     /// Convert to a size.
     pub fn to_size(self) -> Size {
         Size::new(self.x, self.y)
@@ -87,6 +195,34 @@ impl Get<Axis> for Point {
     }
 }
 
+#[cfg(feature = "euclid")]
+impl<U> From<Point> for euclid::Vector2D<f32, U> {
+    fn from(point: Point) -> Self {
+        euclid::Vector2D::new(point.x.to_pt() as f32, point.y.to_pt() as f32)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<U> From<euclid::Vector2D<f32, U>> for Point {
+    fn from(vector: euclid::Vector2D<f32, U>) -> Self {
+        Self::new(Abs::pt(vector.x.into()), Abs::pt(vector.y.into()))
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl From<Point> for kurbo::Point {
+    fn from(point: Point) -> Self {
+        kurbo::Point::new(point.x.to_pt(), point.y.to_pt())
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl From<Point> for kurbo::Vec2 {
+    fn from(point: Point) -> Self {
+        kurbo::Vec2::new(point.x.to_pt(), point.y.to_pt())
+    }
+}
+
 impl Debug for Point {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "Point({:?}, {:?})", self.x, self.y)
@@ -139,3 +275,95 @@ assign_impl!(Point += Point);
 assign_impl!(Point -= Point);
 assign_impl!(Point *= f64);
 assign_impl!(Point /= f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_polar() {
+        let (radius, angle) = Point::new(Abs::pt(3.0), Abs::pt(4.0)).polar();
+        assert_eq!(radius, Abs::pt(5.0));
+        assert!((angle.to_rad() - 4f64.atan2(3.0)).abs() < 1e-9);
+        assert!((Point::with_y(Abs::pt(1.0)).angle().to_deg() - 90.0).abs() < 1e-9);
+        assert_eq!(Point::zero().angle(), Angle::zero());
+    }
+
+    #[test]
+    fn test_point_manhattan() {
+        assert_eq!(Point::new(Abs::pt(3.0), Abs::pt(4.0)).manhattan(), Abs::pt(7.0));
+        assert_eq!(Point::new(Abs::pt(-3.0), Abs::pt(4.0)).manhattan(), Abs::pt(7.0));
+    }
+
+    #[test]
+    fn test_point_chebyshev() {
+        assert_eq!(Point::new(Abs::pt(3.0), Abs::pt(-4.0)).chebyshev(), Abs::pt(4.0));
+    }
+
+    #[test]
+    fn test_point_angle_between() {
+        let x = Point::with_x(Abs::pt(1.0));
+        let y = Point::with_y(Abs::pt(1.0));
+        assert!((x.angle_between(y).to_deg() - 90.0).abs() < 1e-9);
+        assert!((y.angle_between(x).to_deg() + 90.0).abs() < 1e-9);
+        assert_eq!(x.angle_between(Point::zero()), Angle::zero());
+    }
+
+    #[test]
+    fn test_point_reflect() {
+        let point = Point::new(Abs::pt(1.0), Abs::pt(-2.0));
+        assert_eq!(point.reflect_x(), Point::new(Abs::pt(-1.0), Abs::pt(-2.0)));
+        assert_eq!(point.reflect_y(), Point::new(Abs::pt(1.0), Abs::pt(2.0)));
+        assert_eq!(point.reflect_origin(), Point::new(Abs::pt(-1.0), Abs::pt(2.0)));
+        assert_eq!(point.reflect_x().reflect_y(), point.reflect_origin());
+    }
+
+    #[test]
+    fn test_point_snap_to() {
+        let guides = [Abs::zero(), Abs::pt(10.0)];
+        let point = Point::new(Abs::pt(9.0), Abs::pt(5.0));
+        let snapped = point.snap_to(&guides, &guides, Abs::pt(2.0));
+        assert_eq!(snapped, Point::new(Abs::pt(10.0), Abs::pt(5.0)));
+    }
+
+    #[test]
+    fn test_point_grid() {
+        let pt = |x, y| Point::new(Abs::pt(x), Abs::pt(y));
+        let cell = Size::splat(Abs::pt(2.0));
+        let corners: Vec<_> =
+            Point::grid(pt(1.0, 1.0), Size::splat(Abs::pt(4.0)), cell).collect();
+        assert_eq!(corners, [pt(1.0, 1.0), pt(3.0, 1.0), pt(1.0, 3.0), pt(3.0, 3.0)]);
+        assert_eq!(
+            Point::grid(Point::zero(), Size::splat(Abs::pt(5.0)), cell).count(),
+            4
+        );
+        assert_eq!(Point::grid(Point::zero(), cell, Size::zero()).count(), 0);
+    }
+
+    #[test]
+    fn test_point_to_pdf() {
+        let point = Point::new(Abs::pt(10.0), Abs::pt(20.0));
+        assert_eq!(
+            point.to_pdf(Abs::pt(842.0)),
+            Point::new(Abs::pt(10.0), Abs::pt(822.0))
+        );
+        assert_eq!(point.to_pdf(Abs::pt(842.0)).to_pdf(Abs::pt(842.0)), point);
+    }
+
+    #[test]
+    #[cfg(feature = "kurbo")]
+    fn test_point_to_kurbo() {
+        let point = Point::new(Abs::pt(3.0), Abs::pt(4.5));
+        assert_eq!(kurbo::Point::from(point), kurbo::Point::new(3.0, 4.5));
+        assert_eq!(kurbo::Vec2::from(point), kurbo::Vec2::new(3.0, 4.5));
+    }
+
+    #[test]
+    #[cfg(feature = "euclid")]
+    fn test_point_euclid_round_trip() {
+        let point = Point::new(Abs::pt(1.5), Abs::pt(-2.0));
+        let vector: euclid::Vector2D<f32, ()> = point.into();
+        assert_eq!((vector.x, vector.y), (1.5, -2.0));
+        assert_eq!(Point::from(vector), point);
+    }
+}
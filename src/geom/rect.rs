@@ -0,0 +1,128 @@
+use super::*;
+
+/// A rectangle with an origin and a size.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rect {
+    /// The top-left corner of the rectangle.
+    pub origin: Point,
+    /// The extent of the rectangle.
+    pub size: Size,
+}
+
+impl Rect {
+    /// Create a new rectangle from its top-left corner and its size.
+    pub const fn new(origin: Point, size: Size) -> Self {
+        Self { origin, size }
+    }
+
+    /// Create a rectangle at the origin with the given size.
+    pub const fn from_size(size: Size) -> Self {
+        Self { origin: Point::zero(), size }
+    }
+
+    /// The top-left corner.
+    pub fn min(self) -> Point {
+        self.origin
+    }
+
+    /// The bottom-right corner.
+    pub fn max(self) -> Point {
+        self.origin + self.size.to_point()
+    }
+
+    /// Whether the rectangle isn't inverted, that is, its size is
+    /// non-negative along both axes.
+    pub fn is_valid(self) -> bool {
+        self.size.x >= Abs::zero() && self.size.y >= Abs::zero()
+    }
+
+    /// Turn an inverted rectangle into an equivalent one with non-negative
+    /// size by swapping its inverted edges.
+    pub fn normalized(self) -> Self {
+        let (a, b) = (self.min(), self.max());
+        let min = a.min(b);
+        Self { origin: min, size: (a.max(b) - min).to_size() }
+    }
+
+    /// Linearly interpolate the origin and the size between this and another
+    /// rectangle.
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        Self {
+            origin: lerp(self.origin, other.origin, t),
+            size: lerp(self.size, other.size, t),
+        }
+    }
+
+    /// Move the rectangle by the given translation, keeping its size.
+    pub fn translate(self, by: Translation) -> Self {
+        Self { origin: self.origin.translate(by), size: self.size }
+    }
+}
+
+impl Debug for Rect {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Rect({:?}, {:?})", self.origin, self.size)
+    }
+}
+
+impl Add<Translation> for Rect {
+    type Output = Self;
+
+    fn add(self, other: Translation) -> Self {
+        self.translate(other)
+    }
+}
+
+impl Sub<Translation> for Rect {
+    type Output = Self;
+
+    fn sub(self, other: Translation) -> Self {
+        self.translate(-other)
+    }
+}
+
+assign_impl!(Rect += Translation);
+assign_impl!(Rect -= Translation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_translate() {
+        let rect = Rect::new(
+            Point::new(Abs::pt(1.0), Abs::pt(2.0)),
+            Size::new(Abs::pt(10.0), Abs::pt(5.0)),
+        );
+        let moved = rect + Translation::new(Abs::pt(3.0), Abs::pt(-2.0));
+        assert_eq!(moved.size, rect.size);
+        assert_eq!(moved.min(), Point::new(Abs::pt(4.0), Abs::pt(0.0)));
+        assert_eq!(moved.max(), Point::new(Abs::pt(14.0), Abs::pt(5.0)));
+        assert_eq!(moved - Translation::new(Abs::pt(3.0), Abs::pt(-2.0)), rect);
+    }
+
+    #[test]
+    fn test_rect_normalized() {
+        let inverted = Rect::new(
+            Point::new(Abs::pt(10.0), Abs::pt(0.0)),
+            Size::new(Abs::pt(-4.0), Abs::pt(3.0)),
+        );
+        let normalized = inverted.normalized();
+        assert!(!inverted.is_valid());
+        assert!(normalized.is_valid());
+        assert_eq!(normalized.min(), Point::new(Abs::pt(6.0), Abs::pt(0.0)));
+        assert_eq!(normalized.max(), Point::new(Abs::pt(10.0), Abs::pt(3.0)));
+        assert_eq!(normalized.normalized(), normalized);
+    }
+
+    #[test]
+    fn test_rect_lerp() {
+        let a = Rect::from_size(Size::splat(Abs::pt(10.0)));
+        let b = Rect::new(Point::splat(Abs::pt(10.0)), Size::splat(Abs::pt(20.0)));
+        let mid = a.lerp(b, 0.5);
+        assert_eq!(mid.origin, Point::splat(Abs::pt(5.0)));
+        assert_eq!(mid.size, Size::splat(Abs::pt(15.0)));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+}
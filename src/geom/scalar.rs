@@ -6,6 +6,25 @@ use super::*;
 #[derive(Default, Copy, Clone)]
 pub struct Scalar(pub f64);
 
+impl Scalar {
+    /// Apply an arithmetic operation and wrap its result.
+    ///
+    /// With the `debug-nan` feature, this panics in debug builds if the result
+    /// is `NaN` or if it is infinite although both operands are finite, so
+    /// that the operation producing it can be found. Otherwise, this just
+    /// applies the operation.
+    #[inline]
+    pub(super) fn checked(lhs: f64, rhs: f64, op: fn(f64, f64) -> f64) -> Self {
+        let v = op(lhs, rhs);
+        #[cfg(feature = "debug-nan")]
+        debug_assert!(
+            !v.is_nan() && (v.is_finite() || !lhs.is_finite() || !rhs.is_finite()),
+            "arithmetic produced a non-finite value",
+        );
+        Self(v)
+    }
+}
+
 impl Numeric for Scalar {
     fn zero() -> Self {
         Self(0.0)
@@ -96,13 +115,13 @@ impl<T: Into<Self>> Add<T> for Scalar {
     type Output = Self;
 
     fn add(self, rhs: T) -> Self::Output {
-        Self(self.0 + rhs.into().0)
+        Self::checked(self.0, rhs.into().0, |a, b| a + b)
     }
 }
 
 impl<T: Into<Self>> AddAssign<T> for Scalar {
     fn add_assign(&mut self, rhs: T) {
-        self.0 += rhs.into().0;
+        *self = Self::checked(self.0, rhs.into().0, |a, b| a + b);
     }
 }
 
@@ -110,13 +129,13 @@ impl<T: Into<Self>> Sub<T> for Scalar {
     type Output = Self;
 
     fn sub(self, rhs: T) -> Self::Output {
-        Self(self.0 - rhs.into().0)
+        Self::checked(self.0, rhs.into().0, |a, b| a - b)
     }
 }
 
 impl<T: Into<Self>> SubAssign<T> for Scalar {
     fn sub_assign(&mut self, rhs: T) {
-        self.0 -= rhs.into().0;
+        *self = Self::checked(self.0, rhs.into().0, |a, b| a - b);
     }
 }
 
@@ -124,13 +143,13 @@ impl<T: Into<Self>> Mul<T> for Scalar {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
-        Self(self.0 * rhs.into().0)
+        Self::checked(self.0, rhs.into().0, |a, b| a * b)
     }
 }
 
 impl<T: Into<Self>> MulAssign<T> for Scalar {
     fn mul_assign(&mut self, rhs: T) {
-        self.0 *= rhs.into().0;
+        *self = Self::checked(self.0, rhs.into().0, |a, b| a * b);
     }
 }
 
@@ -138,13 +157,13 @@ impl<T: Into<Self>> Div<T> for Scalar {
     type Output = Self;
 
     fn div(self, rhs: T) -> Self::Output {
-        Self(self.0 / rhs.into().0)
+        Self::checked(self.0, rhs.into().0, |a, b| a / b)
     }
 }
 
 impl<T: Into<Self>> DivAssign<T> for Scalar {
     fn div_assign(&mut self, rhs: T) {
-        self.0 /= rhs.into().0;
+        *self = Self::checked(self.0, rhs.into().0, |a, b| a / b);
     }
 }
 
@@ -152,13 +171,13 @@ impl<T: Into<Self>> Rem<T> for Scalar {
     type Output = Self;
 
     fn rem(self, rhs: T) -> Self::Output {
-        Self(self.0 % rhs.into().0)
+        Self::checked(self.0, rhs.into().0, |a, b| a % b)
     }
 }
 
 impl<T: Into<Self>> RemAssign<T> for Scalar {
     fn rem_assign(&mut self, rhs: T) {
-        self.0 %= rhs.into().0;
+        *self = Self::checked(self.0, rhs.into().0, |a, b| a % b);
     }
 }
 
@@ -173,3 +192,37 @@ impl<'a> Sum<&'a Self> for Scalar {
         Self(iter.map(|s| s.0).sum())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "debug-nan", debug_assertions))]
+    #[should_panic(expected = "arithmetic produced a non-finite value")]
+    fn test_scalar_debug_nan() {
+        let _ = Abs::zero() / 0.0;
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-nan", debug_assertions))]
+    #[should_panic(expected = "arithmetic produced a non-finite value")]
+    fn test_scalar_debug_inf() {
+        let _ = Abs::pt(1.0) / 0.0;
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-nan", debug_assertions))]
+    #[should_panic(expected = "arithmetic produced a non-finite value")]
+    fn test_scalar_debug_nan_abs_ratio() {
+        let _ = Abs::zero() / Abs::zero();
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-nan", debug_assertions))]
+    fn test_scalar_debug_inf_operand() {
+        // Infinite operands, like unlimited region sizes, are fine.
+        assert!(!(Abs::inf() - Abs::pt(10.0)).is_finite());
+        assert_eq!(Abs::pt(10.0) / Abs::inf(), 0.0);
+    }
+}
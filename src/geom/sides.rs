@@ -55,6 +55,16 @@ impl<T> Sides<T> {
         }
     }
 
+    /// Swap the left and right sides.
+    pub fn mirror_horizontal(self) -> Self {
+        Self { left: self.right, right: self.left, ..self }
+    }
+
+    /// Swap the top and bottom sides.
+    pub fn mirror_vertical(self) -> Self {
+        Self { top: self.bottom, bottom: self.top, ..self }
+    }
+
     /// An iterator over the sides, starting with the left side, clockwise.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         [&self.left, &self.top, &self.right, &self.bottom].into_iter()
@@ -78,6 +88,11 @@ impl<T: Add> Sides<T> {
 
 impl Sides<Rel<Abs>> {
     /// Evaluate the sides relative to the given `size`.
+    ///
+    /// The left and right sides are resolved relative to the width and the
+    /// top and bottom sides relative to the height. Note that this differs
+    /// from CSS, where percentage padding on all sides is relative to the
+    /// width.
     pub fn relative_to(self, size: Size) -> Sides<Abs> {
         Sides {
             left: self.left.relative_to(size.x),
@@ -88,6 +103,38 @@ impl Sides<Rel<Abs>> {
     }
 }
 
+impl Sides<Abs> {
+    /// The margins that center content of the given size in the available
+    /// space.
+    ///
+    /// The leftover space is split evenly between the opposite sides. Returns
+    /// `None` if the content exceeds the available space on either axis.
+    pub fn around(content: Size, available: Size) -> Option<Self> {
+        if !available.fits(content) {
+            return None;
+        }
+
+        let rest = ((available - content) / 2.0).max(Size::zero());
+        Some(Self::new(rest.x, rest.y, rest.x, rest.y))
+    }
+
+    /// The size that remains of the `available` space when insetting it by the
+    /// sides.
+    ///
+    /// Saturates at zero if the sides exceed the available space. Use
+    /// [`inset_checked`](Self::inset_checked) to detect that case.
+    pub fn inset(self, available: Size) -> Size {
+        (available - self.sum_by_axis()).max(Size::zero())
+    }
+
+    /// The size that remains of the `available` space when insetting it by the
+    /// sides or `None` if the sides don't fit into it.
+    pub fn inset_checked(self, available: Size) -> Option<Size> {
+        let size = available - self.sum_by_axis();
+        size.fits(Size::zero()).then(|| size.max(Size::zero()))
+    }
+}
+
 impl<T> Get<Side> for Sides<T> {
     type Component = T;
 
@@ -262,3 +309,54 @@ impl<T: Fold> Fold for Sides<Option<T>> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sides_around() {
+        let content = Size::new(Abs::pt(6.0), Abs::pt(4.0));
+        let available = Size::new(Abs::pt(10.0), Abs::pt(10.0));
+        assert_eq!(
+            Sides::around(content, available),
+            Some(Sides::new(Abs::pt(2.0), Abs::pt(3.0), Abs::pt(2.0), Abs::pt(3.0))),
+        );
+        assert_eq!(Sides::around(available, content), None);
+    }
+
+    #[test]
+    fn test_sides_mirror() {
+        let sides = Sides::new(Abs::pt(1.0), Abs::pt(2.0), Abs::pt(3.0), Abs::pt(4.0));
+        assert_eq!(
+            sides.mirror_horizontal(),
+            Sides::new(Abs::pt(3.0), Abs::pt(2.0), Abs::pt(1.0), Abs::pt(4.0)),
+        );
+        assert_eq!(
+            sides.mirror_vertical(),
+            Sides::new(Abs::pt(1.0), Abs::pt(4.0), Abs::pt(3.0), Abs::pt(2.0)),
+        );
+    }
+
+    #[test]
+    fn test_sides_inset() {
+        let sides = Sides::splat(Abs::pt(4.0));
+        let small = Size::new(Abs::pt(6.0), Abs::pt(10.0));
+        assert_eq!(sides.inset(small), Size::new(Abs::zero(), Abs::pt(2.0)));
+        assert_eq!(sides.inset_checked(small), None);
+
+        let large = Size::new(Abs::pt(20.0), Abs::pt(10.0));
+        assert_eq!(sides.inset(large), Size::new(Abs::pt(12.0), Abs::pt(2.0)));
+        assert_eq!(sides.inset_checked(large), Some(sides.inset(large)));
+    }
+
+    #[test]
+    fn test_sides_relative_to() {
+        let half = Rel::<Abs>::from(Ratio::new(0.5));
+        let sides = Sides::new(half, half, half + Abs::pt(1.0), half);
+        assert_eq!(
+            sides.relative_to(Size::new(Abs::pt(20.0), Abs::pt(10.0))),
+            Sides::new(Abs::pt(10.0), Abs::pt(5.0), Abs::pt(11.0), Abs::pt(5.0)),
+        );
+    }
+}
@@ -14,6 +14,11 @@ impl Size {
         self.x.fits(other.x) && self.y.fits(other.y)
     }
 
+    /// The area of a rectangle with this size.
+    pub fn area(self) -> Area {
+        Area::pt2(self.x.to_pt() * self.y.to_pt())
+    }
+
     /// Convert to a point.
     pub fn to_point(self) -> Point {
         Point::new(self.x, self.y)
@@ -0,0 +1,93 @@
+use super::*;
+
+/// A relative offset in 2D.
+///
+/// While a [`Point`] is an absolute position, a translation describes a
+/// movement. Translations compose with each other and can be applied to a point
+/// through [`Point::translate`], but two points can't be added up through them.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Translation(pub Point);
+
+impl Translation {
+    /// The zero translation.
+    pub const fn zero() -> Self {
+        Self(Point::zero())
+    }
+
+    /// Create a new translation from horizontal and vertical offsets.
+    pub const fn new(x: Abs, y: Abs) -> Self {
+        Self(Point::new(x, y))
+    }
+
+    /// The horizontal offset.
+    pub const fn x(self) -> Abs {
+        self.0.x
+    }
+
+    /// The vertical offset.
+    pub const fn y(self) -> Abs {
+        self.0.y
+    }
+}
+
+impl Debug for Translation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Translation({:?}, {:?})", self.0.x, self.0.y)
+    }
+}
+
+impl Neg for Translation {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Add for Translation {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+sub_impl!(Translation - Translation -> Translation);
+
+assign_impl!(Translation += Translation);
+assign_impl!(Translation -= Translation);
+
+impl Add<Translation> for Point {
+    type Output = Self;
+
+    fn add(self, other: Translation) -> Self {
+        self.translate(other)
+    }
+}
+
+impl Sub<Translation> for Point {
+    type Output = Self;
+
+    fn sub(self, other: Translation) -> Self {
+        self.translate(-other)
+    }
+}
+
+assign_impl!(Point += Translation);
+assign_impl!(Point -= Translation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translation_compose_and_apply() {
+        let a = Translation::new(Abs::pt(1.0), Abs::pt(2.0));
+        let b = Translation::new(Abs::pt(3.0), Abs::pt(-4.0));
+        let p = Point::new(Abs::pt(10.0), Abs::pt(10.0));
+        assert_eq!(p.translate(a), Point::new(Abs::pt(11.0), Abs::pt(12.0)));
+        assert_eq!(p.translate(a + b), p.translate(a).translate(b));
+        assert_eq!(p.translate(a).translate(-a), p);
+        assert_eq!(p + a - a, p);
+    }
+}
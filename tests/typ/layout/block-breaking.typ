@@ -0,0 +1,38 @@
+// Test that an unbreakable block is broken anyway if it doesn't fit onto an
+// empty page.
+// Ref: false
+
+---
+#set page(height: 80pt)
+#block(breakable: false)[#lorem(40)#box[]<end>]
+#locate(loc => {
+  let end = query(<end>, loc)
+  if end.len() > 0 {
+    test(end.first().location().page() > 1, true)
+  }
+})
+
+---
+// A block that fits onto an empty page is not broken.
+#set page(height: 80pt)
+#lorem(5)
+#block(breakable: false)[#box[]<start>#lorem(8)#box[]<end>]
+#locate(loc => {
+  let start = query(<start>, loc)
+  let end = query(<end>, loc)
+  if start.len() > 0 and end.len() > 0 {
+    test(start.first().location().page(), end.first().location().page())
+  }
+})
+
+---
+// Fractional spacing in an unbreakable block still fills the region.
+#set page(height: 100pt, margin: 10pt)
+#block(breakable: false)[A #v(1fr) B#box[]<end>]
+#locate(loc => {
+  let end = query(<end>, loc)
+  if end.len() > 0 {
+    test(end.first().location().page(), 1)
+    test(end.first().location().position().y > 70pt, true)
+  }
+})
@@ -0,0 +1,21 @@
+// Test measuring content.
+// Ref: false
+
+---
+// Test that the natural width is used by default.
+#style(styles => {
+  let body = [Hello World]
+  let natural = measure(body, styles)
+  let wide = measure(body, styles, width: 1000pt)
+  test(natural, wide)
+})
+
+---
+// Test that a limited width makes the content wrap.
+#style(styles => {
+  let body = lorem(20)
+  let natural = measure(body, styles)
+  let wrapped = measure(body, styles, width: 60pt)
+  test(wrapped.width <= 60pt, true)
+  test(wrapped.height > natural.height, true)
+})
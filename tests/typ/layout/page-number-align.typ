@@ -0,0 +1,70 @@
+// Test page numbering alignment.
+// Ref: false
+
+---
+// With top alignment, the number sits in the header, at its baseline
+// rather than the top edge of the page.
+#set page(
+  height: 100pt,
+  margin: (top: 30pt, rest: 10pt),
+  number-align: top + right,
+  numbering: (..nums) => locate(loc => {
+    if query(<body>, loc).len() > 0 {
+      test(loc.position().y > 10pt, true)
+      test(loc.page(), 1)
+    }
+    str(nums.pos().first())
+  }),
+)
+
+#box[]<body>
+
+---
+// In the footer, the vertical alignment still applies.
+#set page(
+  height: 100pt,
+  margin: (bottom: 40pt, rest: 10pt),
+  number-align: bottom + left,
+  numbering: (..nums) => locate(loc => {
+    if query(<body>, loc).len() > 0 {
+      test(loc.position().y > 80pt, true)
+    }
+    str(nums.pos().first())
+  }),
+)
+
+#box[]<body>
+
+---
+// The total number of pages is passed along with the current one.
+#set page(
+  height: 100pt,
+  numbering: (..nums) => locate(loc => {
+    if query(<end>, loc).len() > 0 {
+      test(nums.pos(), (loc.page(), 3))
+      test(numbering("1 / 1", ..nums.pos()), str(loc.page()) + " / 3")
+    }
+    numbering("1 / 1", ..nums)
+  }),
+)
+
+A #pagebreak() B #pagebreak() C #box[]<end>
+
+---
+// Roman page numbers.
+#set page(
+  height: 100pt,
+  numbering: (..nums) => locate(loc => {
+    if query(<end>, loc).len() > 0 {
+      let expected = ("i / I", "ii / II", "iii / III").at(loc.page() - 1)
+      test(numbering("i / I", nums.pos().first(), nums.pos().first()), expected)
+    }
+    numbering("i", nums.pos().first())
+  }),
+)
+
+A #pagebreak() B #pagebreak() C #box[]<end>
+
+---
+// Error: 25-32 page number cannot be `horizon`-aligned
+#set page(number-align: horizon)
@@ -10,6 +10,15 @@ What's up?
 // Ref: false
 #set document(author: ("A", "B"))
 
+---
+// This, too.
+// Ref: false
+#set document(subject: "Testing", created: "2023-04-15", modified: "2023-05-02")
+
+---
+// Error: 24-36 day is out of range for the month
+#set document(created: "2023-02-30")
+
 ---
 // This, too.
 // Error: 23-29 expected string, found integer
@@ -53,6 +53,27 @@ The keyword ```rust let```.
 #show raw: set text(font: "Roboto")
 `Roboto`
 
+---
+// Tabs advance to the next multiple of the tab size, counted from the start
+// of the line.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width(raw("\tx")), width(raw("  x")))
+  test(width(raw("abc\tx")), width(raw("abc x")))
+})
+
+---
+// Custom tab size with tabs after text in the middle of a column.
+// Ref: false
+#set raw(tab-size: 4)
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width(raw("a\tb")), width(raw("a   b")))
+  test(width(raw("abcd\tx")), width(raw("abcd    x")))
+  test(width(raw("a\tb\nabc\td")), width(raw("a   b\nabc d")))
+})
+
 ---
 // Unterminated.
 // Error: 2:1 expected 1 backtick